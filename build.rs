@@ -0,0 +1,101 @@
+//! Generates the registry of solvers from the files in `src/solvers/yearNNNN/dayNN.rs`.
+//!
+//! A day is registered as parsed if `part1` or `part2` take something other than the raw `&str`
+//! input, in which case the day has to provide a `parse` function producing that input. Days can
+//! declare their puzzle title and tags as `pub const TITLE: &str` and `pub const TAGS: &[&str]`.
+
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn main() {
+    let solvers = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("src/solvers");
+    println!("cargo:rerun-if-changed={}", solvers.display());
+
+    let mut out = String::new();
+    let years = numbered_entries(&solvers, "year", "");
+    for (year, dir) in &years {
+        writeln!(out, "pub mod year{year} {{").unwrap();
+        let days = numbered_entries(dir, "day", ".rs");
+        for (day, path) in &days {
+            writeln!(out, "    #[path = {:?}]\n    mod day{day};", path.display()).unwrap();
+        }
+        writeln!(out, "\n    pub static DAYS: &[crate::solvers::Day] = &[").unwrap();
+        for (day, path) in &days {
+            let source = fs::read_to_string(path).unwrap();
+            let kind = if is_parsed(&source) { " parsed" } else { "" };
+            let title = if declares(&source, "TITLE") {
+                format!("Some(day{day}::TITLE)")
+            } else {
+                "None".to_string()
+            };
+            let tags = if declares(&source, "TAGS") {
+                format!("day{day}::TAGS")
+            } else {
+                "&[]".to_string()
+            };
+            let implemented = ["part1_todo!", "part2_todo!"].map(|todo| !source.contains(todo));
+            writeln!(
+                out,
+                "        crate::solvers::Day {{\n            \
+                 name: \"day{day}\",\n            \
+                 title: {title},\n            \
+                 tags: {tags},\n            \
+                 implemented: {implemented:?},\n            \
+                 solver: crate::day_solver!(day{day}{kind}),\n        }},"
+            )
+            .unwrap();
+        }
+        writeln!(out, "    ];\n}}\n").unwrap();
+    }
+
+    writeln!(out, "pub static YEARS: &[(&str, &[Day])] = &[").unwrap();
+    for (year, _) in &years {
+        writeln!(out, "    (\"{year}\", year{year}::DAYS),").unwrap();
+    }
+    writeln!(out, "];").unwrap();
+
+    let path = Path::new(&env::var("OUT_DIR").unwrap()).join("registry.rs");
+    fs::write(path, out).unwrap();
+}
+
+/// The entries of `dir` named `{prefix}{number}{suffix}`, sorted by name
+fn numbered_entries(dir: &Path, prefix: &str, suffix: &str) -> Vec<(String, PathBuf)> {
+    let mut entries: Vec<(String, PathBuf)> = fs::read_dir(dir)
+        .unwrap()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let name = path.file_name()?.to_str()?;
+            let number = name.strip_prefix(prefix)?.strip_suffix(suffix)?.to_string();
+            // years are directories, days are files
+            let valid = !number.is_empty()
+                && number.bytes().all(|b| b.is_ascii_digit())
+                && path.is_dir() == suffix.is_empty();
+            valid.then_some((number, path))
+        })
+        .collect();
+    entries.sort();
+    entries
+}
+
+/// Whether the day declares a public constant with the given name
+fn declares(source: &str, name: &str) -> bool {
+    source.contains(&format!("\npub const {name}:"))
+        || source.starts_with(&format!("pub const {name}:"))
+}
+
+/// Whether one of the parts of a day takes a parsed input instead of the raw `&str`
+fn is_parsed(source: &str) -> bool {
+    ["part1", "part2"].iter().any(|part| {
+        source
+            .split(&format!("\npub fn {part}("))
+            .skip(1)
+            .any(|rest| {
+                let parameter = rest.split(')').next().unwrap_or_default();
+                parameter
+                    .split_once(':')
+                    .is_some_and(|(_, ty)| ty.trim().trim_end_matches(',') != "&str")
+            })
+    })
+}
@@ -0,0 +1,330 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use comfy_table::{Cell, Color, Table};
+
+use crate::input::{self, SolutionMode};
+use crate::output::format_duration;
+use crate::runner::{self, Isolated};
+use crate::solvers::{self, Day, SolveError, Solver};
+
+/// Summary statistics over the samples of a benchmarked part
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Computes the statistics of the given samples. Returns `None` if there are no samples.
+    pub fn new(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let mid = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[mid - 1] + sorted[mid]) / 2
+        } else {
+            sorted[mid]
+        };
+
+        let secs: Vec<f64> = sorted.iter().map(Duration::as_secs_f64).collect();
+        let mean = secs.iter().sum::<f64>() / secs.len() as f64;
+        let variance = secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / secs.len() as f64;
+
+        Some(Stats {
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+/// The benchmark result of a single part
+pub struct Benchmark {
+    pub year: &'static str,
+    pub day: &'static str,
+    pub part: &'static str,
+    pub stats: Stats,
+}
+
+impl Benchmark {
+    fn key(&self) -> String {
+        format!("{} {} {}", self.year, self.day, self.part)
+    }
+}
+
+/// Benchmarks the parts of the given days with their real inputs, or only the given part. Days
+/// with a parse step also benchmark parsing separately. Parts that fail to solve or panic are
+/// reported on stderr and skipped, parts that are not implemented are skipped silently.
+pub fn run(
+    days: &[(&'static str, &'static Day)],
+    iterations: usize,
+    warmup: usize,
+    part: Option<u8>,
+) -> Vec<Benchmark> {
+    let mut benchmarks = vec![];
+    for (year, day) in days {
+        let input = match input::get_problem(year, day.name, SolutionMode::default()) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("Failed to get input for {year} {}: {e}", day.name);
+                continue;
+            }
+        };
+
+        let runs = |number: &str| part.is_none_or(|part| part.to_string() == number);
+        let mut record = |part, samples: Isolated<Result<Vec<Duration>, SolveError>>| match samples
+        {
+            Ok((Ok(samples), _)) => benchmarks.push(Benchmark {
+                year,
+                day: day.number(),
+                part,
+                // there is at least one sample because iterations is at least one
+                stats: Stats::new(&samples).unwrap(),
+            }),
+            Ok((Err(SolveError::Unimplemented), _)) => {}
+            Ok((Err(e), _)) => eprintln!("Failed to solve {year} {} part {part}: {e}", day.name),
+            Err(e) => eprintln!("Failed to solve {year} {} part {part}: {e}", day.name),
+        };
+
+        let text1: Arc<str> = input.part1.into();
+        let text2: Arc<str> = input.part2.map_or_else(|| text1.clone(), Arc::from);
+        solvers::with_params(&input.params, || match day.solver {
+            Solver::Direct { part1, part2 } => {
+                if runs("1") {
+                    let text = text1.clone();
+                    record(
+                        "1",
+                        sample_isolated(move || part1(&text), iterations, warmup),
+                    );
+                }
+                if runs("2") {
+                    let text = text2.clone();
+                    record(
+                        "2",
+                        sample_isolated(move || part2(&text), iterations, warmup),
+                    );
+                }
+            }
+            Solver::Parsed {
+                parse,
+                part1,
+                part2,
+            } => {
+                let text = text1.clone();
+                record(
+                    "parse",
+                    sample_isolated(move || parse(&text), iterations, warmup),
+                );
+                let parse_once = |text: &Arc<str>| {
+                    let text = text.clone();
+                    match runner::isolated(None, move || parse(&text)) {
+                        Ok((Ok(parsed), _)) => Some(Arc::new(parsed)),
+                        _ => None,
+                    }
+                };
+                let (Some(parsed1), Some(parsed2)) = (parse_once(&text1), parse_once(&text2))
+                else {
+                    eprintln!("Failed to parse input for {year} {}", day.name);
+                    return;
+                };
+                if runs("1") {
+                    record(
+                        "1",
+                        sample_isolated(move || part1(&**parsed1), iterations, warmup),
+                    );
+                }
+                if runs("2") {
+                    record(
+                        "2",
+                        sample_isolated(move || part2(&**parsed2), iterations, warmup),
+                    );
+                }
+            }
+        });
+    }
+    benchmarks
+}
+
+/// Samples `solve` in isolation, so a panicking solver does not abort the benchmarks
+fn sample_isolated<T>(
+    solve: impl Fn() -> Result<T, SolveError> + Send + 'static,
+    iterations: usize,
+    warmup: usize,
+) -> Isolated<Result<Vec<Duration>, SolveError>> {
+    runner::isolated(None, move || sample(solve, iterations, warmup))
+}
+
+fn sample<T>(
+    solve: impl Fn() -> Result<T, SolveError>,
+    iterations: usize,
+    warmup: usize,
+) -> Result<Vec<Duration>, SolveError> {
+    for _ in 0..warmup {
+        solve()?;
+    }
+    (0..iterations.max(1))
+        .map(|_| {
+            let now = Instant::now();
+            solve()?;
+            Ok(now.elapsed())
+        })
+        .collect()
+}
+
+/// Writes the medians of the benchmarks to a baseline file, one part per line
+pub fn save_baseline(path: &Path, benchmarks: &[Benchmark]) -> std::io::Result<()> {
+    let mut out = String::new();
+    for benchmark in benchmarks {
+        writeln!(
+            out,
+            "{} {}",
+            benchmark.key(),
+            benchmark.stats.median.as_nanos()
+        )
+        .unwrap();
+    }
+    fs::write(path, out)
+}
+
+/// Reads the medians of a baseline file written by [`save_baseline`]
+pub fn load_baseline(path: &Path) -> Result<HashMap<String, Duration>, String> {
+    let text = fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()))?;
+    text.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let (key, nanos) = line
+                .trim()
+                .rsplit_once(' ')
+                .ok_or_else(|| format!("invalid baseline line: {line}"))?;
+            let nanos: u64 = nanos
+                .parse()
+                .map_err(|e| format!("invalid baseline line: {line}: {e}"))?;
+            Ok((key.to_string(), Duration::from_nanos(nanos)))
+        })
+        .collect()
+}
+
+/// The relative change of the median compared to the baseline in percent
+fn change_percent(median: Duration, baseline: Duration) -> f64 {
+    (median.as_secs_f64() / baseline.as_secs_f64() - 1.0) * 100.0
+}
+
+/// Returns the benchmarks whose median got slower than the baseline by more than the threshold
+pub fn regressions<'a>(
+    benchmarks: &'a [Benchmark],
+    baseline: &HashMap<String, Duration>,
+    threshold_percent: f64,
+) -> Vec<&'a Benchmark> {
+    benchmarks
+        .iter()
+        .filter(|b| {
+            baseline
+                .get(&b.key())
+                .is_some_and(|base| change_percent(b.stats.median, *base) > threshold_percent)
+        })
+        .collect()
+}
+
+pub fn render(
+    benchmarks: &[Benchmark],
+    baseline: Option<&HashMap<String, Duration>>,
+    threshold_percent: f64,
+) -> String {
+    let mut table = Table::new();
+    let mut header = vec!["year", "day", "part", "min", "median", "mean", "stddev"];
+    if baseline.is_some() {
+        header.extend(["baseline", "change"]);
+    }
+    table.set_header(header);
+
+    for benchmark in benchmarks {
+        let stats = benchmark.stats;
+        let mut row = vec![
+            Cell::new(benchmark.year),
+            Cell::new(benchmark.day),
+            Cell::new(benchmark.part),
+            Cell::new(format_duration(stats.min)),
+            Cell::new(format_duration(stats.median)),
+            Cell::new(format_duration(stats.mean)),
+            Cell::new(format_duration(stats.stddev)),
+        ];
+        if let Some(baseline) = baseline {
+            match baseline.get(&benchmark.key()) {
+                Some(base) => {
+                    let change = change_percent(stats.median, *base);
+                    let color = if change > threshold_percent {
+                        Color::Red
+                    } else if change < -threshold_percent {
+                        Color::Green
+                    } else {
+                        Color::Reset
+                    };
+                    row.push(Cell::new(format_duration(*base)));
+                    row.push(Cell::new(format!("{change:+.1}%")).fg(color));
+                }
+                None => row.extend([Cell::new("-"), Cell::new("-")]),
+            }
+        }
+        table.add_row(row);
+    }
+    table.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn stats() {
+        assert_eq!(Stats::new(&[]), None);
+
+        let stats = Stats::new(&[ms(4), ms(1), ms(3), ms(2)]).unwrap();
+        assert_eq!(stats.min, ms(1));
+        assert_eq!(stats.median, Duration::from_micros(2_500));
+        assert_eq!(stats.mean, Duration::from_micros(2_500));
+        assert_eq!(stats.stddev.as_micros(), 1_118);
+
+        let stats = Stats::new(&[ms(5), ms(1), ms(3)]).unwrap();
+        assert_eq!(stats.median, ms(3));
+    }
+
+    #[test]
+    fn regressions_above_threshold() {
+        let benchmark = |part, median| Benchmark {
+            year: "2025",
+            day: "01",
+            part,
+            stats: Stats {
+                min: median,
+                median,
+                mean: median,
+                stddev: Duration::ZERO,
+            },
+        };
+        let benchmarks = [benchmark("1", ms(111)), benchmark("2", ms(109))];
+        let baseline = HashMap::from([
+            ("2025 01 1".to_string(), ms(100)),
+            ("2025 01 2".to_string(), ms(100)),
+        ]);
+
+        let slower = regressions(&benchmarks, &baseline, 10.0);
+        assert_eq!(slower.len(), 1);
+        assert_eq!(slower[0].part, "1");
+    }
+}
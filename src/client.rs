@@ -0,0 +1,371 @@
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use crate::input;
+
+/// The site that is used if no other base URL is configured
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Sends HTTP requests with the session cookie, so the client can be tested without a network
+pub trait Transport {
+    /// Sends a GET request and returns the body of the response
+    fn get(&self, url: &str, session: &str) -> Result<String, String>;
+    /// Sends a url encoded form in a POST request and returns the body of the response
+    fn post(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<String, String>;
+}
+
+/// Sends requests with the `curl` command line tool
+pub struct Curl;
+
+impl Curl {
+    fn request(&self, url: &str, session: &str, args: &[String]) -> Result<String, String> {
+        let mut child = Command::new("curl")
+            .args(["--silent", "--show-error", "--fail", "--location"])
+            .args([
+                "--user-agent",
+                "aoc-rust (https://github.com/julian-go/aoc-rust)",
+            ])
+            // the cookie is read from stdin so it does not show up in the process list
+            .args(["--header", "@-"])
+            .args(args)
+            .arg(url)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| format!("failed to run curl: {e}"))?;
+        // unwrapping here is safe because stdin is piped
+        let mut stdin = child.stdin.take().unwrap();
+        writeln!(stdin, "Cookie: session={session}").map_err(|e| e.to_string())?;
+        drop(stdin);
+
+        let output = child.wait_with_output().map_err(|e| e.to_string())?;
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+        }
+        String::from_utf8(output.stdout).map_err(|e| e.to_string())
+    }
+}
+
+impl Transport for Curl {
+    fn get(&self, url: &str, session: &str) -> Result<String, String> {
+        self.request(url, session, &[])
+    }
+
+    fn post(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<String, String> {
+        let args: Vec<String> = form
+            .iter()
+            .flat_map(|(key, value)| ["--data-urlencode".to_string(), format!("{key}={value}")])
+            .collect();
+        self.request(url, session, &args)
+    }
+}
+
+/// The response of the site to a submitted answer
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// An answer was submitted too recently, the message says how long to wait
+    RateLimited(String),
+    /// The part was solved before or is not unlocked yet
+    WrongLevel,
+    /// The response could not be interpreted, it contains the text of the response
+    Unknown(String),
+}
+
+impl Verdict {
+    /// Interprets the HTML page returned after submitting an answer
+    pub fn parse(page: &str) -> Verdict {
+        let text = main_text(page);
+        if text.contains("That's the right answer") {
+            Verdict::Correct
+        } else if text.contains("You gave an answer too recently") {
+            let wait = text
+                .split_once("You have ")
+                .and_then(|(_, rest)| rest.split_once(" left to wait"))
+                .map_or("", |(wait, _)| wait);
+            Verdict::RateLimited(wait.to_string())
+        } else if text.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if text.contains("your answer is too low") {
+            Verdict::TooLow
+        } else if text.contains("That's not the right answer") {
+            Verdict::Wrong
+        } else if text.contains("You don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            Verdict::Unknown(text)
+        }
+    }
+}
+
+/// The text inside the `<main>` element of a page with all tags removed
+fn main_text(page: &str) -> String {
+    let main = page
+        .split_once("<main>")
+        .and_then(|(_, rest)| rest.split_once("</main>"))
+        .map_or(page, |(main, _)| main);
+    strip_tags(main)
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Removes the tags from HTML and decodes the entities the site uses
+fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Extracts the example input from a puzzle page. This is the first code block introduced by a
+/// paragraph mentioning an example, or the first code block if no paragraph does.
+fn extract_example(page: &str) -> Option<String> {
+    let mut blocks = page.split("<pre><code>");
+    let mut before = blocks.next()?;
+    let mut first = None;
+    for block in blocks {
+        let code = strip_tags(block.split_once("</code></pre>")?.0);
+        let intro = before
+            .rsplit_once("</pre>")
+            .map_or(before, |(_, intro)| intro);
+        if intro.to_lowercase().contains("example") {
+            return Some(code);
+        }
+        first.get_or_insert(code);
+        before = block;
+    }
+    first
+}
+
+/// Whether the input of a day has to be downloaded. An encrypted input counts as present,
+/// downloading it again would write the plain input.
+fn input_missing(dir: &Path) -> bool {
+    let input = dir.join("part1.txt");
+    !input.exists() && !input::encrypted_path(&input).is_file()
+}
+
+/// Whether the example of a day has to be downloaded. An empty example is a placeholder, e.g. one
+/// created by the new command.
+fn example_missing(dir: &Path) -> bool {
+    !fs::metadata(dir.join("part1_ex.txt")).is_ok_and(|metadata| metadata.len() > 0)
+}
+
+/// Whether the input or the example of a day is not in the inputs tree yet
+pub fn is_missing(year: &str, day_name: &str) -> bool {
+    let dir = input::day_dir(year, day_name);
+    input_missing(&dir) || example_missing(&dir)
+}
+
+/// A client for the puzzle site, authenticated with a session token
+pub struct Client {
+    pub base_url: String,
+    pub session: String,
+    pub transport: Box<dyn Transport>,
+}
+
+impl Client {
+    fn day_url(&self, year: &str, day: &str) -> String {
+        // the site does not use leading zeros for days
+        let day = day.trim_start_matches('0');
+        format!("{}/{year}/day/{day}", self.base_url.trim_end_matches('/'))
+    }
+
+    /// Downloads the input of a day, the day is given without the `day` prefix
+    pub fn fetch_input(&self, year: &str, day: &str) -> Result<String, String> {
+        self.transport
+            .get(&format!("{}/input", self.day_url(year, day)), &self.session)
+    }
+
+    /// Downloads the puzzle page of a day and extracts its example
+    pub fn fetch_example(&self, year: &str, day: &str) -> Result<Option<String>, String> {
+        let page = self
+            .transport
+            .get(&self.day_url(year, day), &self.session)?;
+        Ok(extract_example(&page))
+    }
+
+    /// Downloads the input and the example of a day if they are not in the inputs tree yet and
+    /// returns the files that were written. Existing files are never downloaded again.
+    pub fn fetch_missing(&self, year: &str, day_name: &str) -> Result<Vec<PathBuf>, String> {
+        let dir = input::day_dir(year, day_name);
+        let day = day_name.trim_start_matches("day");
+        let mut written = vec![];
+        let mut write = |file: &str, contents: String| {
+            fs::create_dir_all(&dir).map_err(|e| format!("{}: {e}", dir.display()))?;
+            let path = dir.join(file);
+            fs::write(&path, contents).map_err(|e| format!("{}: {e}", path.display()))?;
+            written.push(path);
+            Ok::<_, String>(())
+        };
+
+        if input_missing(&dir) {
+            write("part1.txt", self.fetch_input(year, day)?)?;
+        }
+        if example_missing(&dir)
+            && let Some(example) = self.fetch_example(year, day)?
+        {
+            write("part1_ex.txt", example)?;
+        }
+        Ok(written)
+    }
+
+    /// Submits the answer of a part, the day is given without the `day` prefix
+    pub fn submit(&self, year: &str, day: &str, part: u8, answer: &str) -> Result<Verdict, String> {
+        let url = format!("{}/answer", self.day_url(year, day));
+        let level = part.to_string();
+        let page = self.transport.post(
+            &url,
+            &self.session,
+            &[("level", &level), ("answer", answer)],
+        )?;
+        Ok(Verdict::parse(&page))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::*;
+
+    fn page(main: &str) -> String {
+        format!("<html><body><main>\n<article><p>{main}</p></article>\n</main></body></html>")
+    }
+
+    #[test]
+    fn parse_verdicts() {
+        assert_eq!(
+            Verdict::parse(&page(
+                "That's the right answer! You are <em>one gold star</em>"
+            )),
+            Verdict::Correct
+        );
+        assert_eq!(
+            Verdict::parse(&page(
+                "That's not the right answer; your answer is too high. Please wait one minute."
+            )),
+            Verdict::TooHigh
+        );
+        assert_eq!(
+            Verdict::parse(&page(
+                "That's not the right answer; your answer is too low."
+            )),
+            Verdict::TooLow
+        );
+        assert_eq!(
+            Verdict::parse(&page("That's not the right answer. If you're stuck, ...")),
+            Verdict::Wrong
+        );
+        assert_eq!(
+            Verdict::parse(&page(
+                "You gave an answer too recently. You have 34s left to wait."
+            )),
+            Verdict::RateLimited("34s".to_string())
+        );
+        assert_eq!(
+            Verdict::parse(&page("You don't seem to be solving the right level.")),
+            Verdict::WrongLevel
+        );
+        assert_eq!(
+            Verdict::parse("<p>Something\n else</p>"),
+            Verdict::Unknown("Something else".to_string())
+        );
+    }
+
+    /// Records the requests and answers all of them with the same response
+    struct Recorder {
+        requests: Rc<RefCell<Vec<String>>>,
+        response: String,
+    }
+
+    impl Transport for Recorder {
+        fn get(&self, url: &str, session: &str) -> Result<String, String> {
+            self.requests
+                .borrow_mut()
+                .push(format!("GET {url} {session}"));
+            Ok(self.response.clone())
+        }
+
+        fn post(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<String, String> {
+            self.requests
+                .borrow_mut()
+                .push(format!("POST {url} {session} {form:?}"));
+            Ok(self.response.clone())
+        }
+    }
+
+    #[test]
+    fn extract_examples() {
+        let page = "<main><article><p>The map looks like this:</p>\n\
+                    <pre><code>..#\n</code></pre>\n\
+                    <p>For example, suppose you have:</p>\n\
+                    <pre><code>1 &lt; 2\n<em>3</em> &amp; 4\n</code></pre></article></main>";
+        assert_eq!(extract_example(page), Some("1 < 2\n3 & 4\n".to_string()));
+        assert_eq!(
+            extract_example("<pre><code>only\n</code></pre>"),
+            Some("only\n".to_string())
+        );
+        assert_eq!(extract_example("<p>no code</p>"), None);
+    }
+
+    #[test]
+    fn fetch_uses_day_urls() {
+        let requests = Rc::new(RefCell::new(vec![]));
+        let client = Client {
+            base_url: "http://localhost:8080".to_string(),
+            session: "abc".to_string(),
+            transport: Box::new(Recorder {
+                requests: requests.clone(),
+                response: "1\n2\n".to_string(),
+            }),
+        };
+        assert_eq!(client.fetch_input("2025", "09"), Ok("1\n2\n".to_string()));
+        assert_eq!(client.fetch_example("2025", "10"), Ok(None));
+        assert_eq!(
+            *requests.borrow(),
+            [
+                "GET http://localhost:8080/2025/day/9/input abc",
+                "GET http://localhost:8080/2025/day/10 abc"
+            ]
+        );
+    }
+
+    #[test]
+    fn submit_posts_answer() {
+        let requests = Rc::new(RefCell::new(vec![]));
+        let client = Client {
+            base_url: "http://localhost:8080/".to_string(),
+            session: "abc".to_string(),
+            transport: Box::new(Recorder {
+                requests: requests.clone(),
+                response: page("That's the right answer!"),
+            }),
+        };
+        assert_eq!(client.submit("2025", "01", 2, "42"), Ok(Verdict::Correct));
+        assert_eq!(
+            *requests.borrow(),
+            [
+                "POST http://localhost:8080/2025/day/1/answer abc [(\"level\", \"2\"), (\"answer\", \"42\")]"
+            ]
+        );
+    }
+}
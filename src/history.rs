@@ -0,0 +1,179 @@
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::PathBuf;
+
+use clap::ValueEnum;
+
+use crate::input;
+
+/// Why an answer was rejected
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Hint {
+    /// The answer is wrong without further hints
+    Wrong,
+    /// The answer is too high
+    High,
+    /// The answer is too low
+    Low,
+}
+
+impl Hint {
+    fn as_str(self) -> &'static str {
+        match self {
+            Hint::Wrong => "wrong",
+            Hint::High => "high",
+            Hint::Low => "low",
+        }
+    }
+}
+
+/// A rejected answer of a part
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rejection {
+    pub part: u8,
+    pub hint: Hint,
+    pub answer: String,
+}
+
+/// The answers of a day that were rejected before, stored in `history.txt` in the directory of
+/// the day with one `<part> <wrong|high|low> <answer>` line per answer
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct History {
+    pub rejections: Vec<Rejection>,
+}
+
+fn path(year: &str, day: &str) -> PathBuf {
+    input::day_dir(year, day).join("history.txt")
+}
+
+impl History {
+    /// Loads the history of a day, days without a history file have an empty history
+    pub fn load(year: &str, day: &str) -> Result<History, String> {
+        let path = path(year, day);
+        match fs::read_to_string(&path) {
+            Ok(text) => History::parse(&text).map_err(|e| format!("{}: {e}", path.display())),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(History::default()),
+            Err(e) => Err(format!("{}: {e}", path.display())),
+        }
+    }
+
+    fn parse(text: &str) -> Result<History, String> {
+        let rejections = text
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| {
+                let error = || format!("line {}: expected '<part> <hint> <answer>'", i + 1);
+                let mut fields = line.trim().splitn(3, ' ');
+                let part = fields.next().and_then(|part| part.parse().ok());
+                let hint = fields
+                    .next()
+                    .and_then(|hint| Hint::from_str(hint, false).ok());
+                match (part, hint, fields.next()) {
+                    (Some(part @ 1..=2), Some(hint), Some(answer)) => Ok(Rejection {
+                        part,
+                        hint,
+                        answer: answer.to_string(),
+                    }),
+                    _ => Err(error()),
+                }
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(History { rejections })
+    }
+
+    /// Appends a rejected answer to the history file of a day
+    pub fn record(year: &str, day: &str, rejection: &Rejection) -> io::Result<()> {
+        let path = path(year, day);
+        fs::create_dir_all(path.parent().unwrap())?;
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(
+            file,
+            "{} {} {}",
+            rejection.part,
+            rejection.hint.as_str(),
+            rejection.answer
+        )
+    }
+
+    /// Checks that an answer was not rejected before and lies within the bounds of the answers
+    /// that were too high or too low. Returns why the answer is wrong otherwise.
+    pub fn check(&self, part: u8, answer: &str) -> Result<(), String> {
+        let answer = answer.trim();
+        let rejections = self.rejections.iter().filter(|r| r.part == part);
+        if rejections.clone().any(|r| r.answer == answer) {
+            return Err(format!("{answer} was rejected before"));
+        }
+
+        let Ok(number) = answer.parse::<i128>() else {
+            return Ok(());
+        };
+        let bound = |hint| {
+            rejections
+                .clone()
+                .filter(move |r| r.hint == hint)
+                .filter_map(|r| r.answer.parse::<i128>().ok())
+        };
+        if let Some(low) = bound(Hint::Low).max().filter(|low| number <= *low) {
+            return Err(format!(
+                "{answer} is not higher than {low}, which was too low"
+            ));
+        }
+        if let Some(high) = bound(Hint::High).min().filter(|high| number >= *high) {
+            return Err(format!(
+                "{answer} is not lower than {high}, which was too high"
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_history() {
+        let history = History::parse("1 wrong 42\n\n2 high 1 000\n").unwrap();
+        assert_eq!(
+            history.rejections,
+            [
+                Rejection {
+                    part: 1,
+                    hint: Hint::Wrong,
+                    answer: "42".to_string()
+                },
+                Rejection {
+                    part: 2,
+                    hint: Hint::High,
+                    answer: "1 000".to_string()
+                },
+            ]
+        );
+        assert!(History::parse("3 wrong 42").is_err());
+        assert!(History::parse("1 maybe 42").is_err());
+        assert!(History::parse("1 low").is_err());
+    }
+
+    #[test]
+    fn check_answers() {
+        let history =
+            History::parse("1 wrong 42\n1 low 10\n1 low 20\n1 high 100\n2 wrong abc").unwrap();
+        assert_eq!(
+            history.check(1, "42"),
+            Err("42 was rejected before".to_string())
+        );
+        assert_eq!(
+            history.check(1, "15"),
+            Err("15 is not higher than 20, which was too low".to_string())
+        );
+        assert_eq!(
+            history.check(1, "150"),
+            Err("150 is not lower than 100, which was too high".to_string())
+        );
+        assert_eq!(history.check(1, "50"), Ok(()));
+        assert_eq!(history.check(2, "42"), Ok(()));
+        assert!(history.check(2, "abc").is_err());
+        assert_eq!(history.check(2, "abd"), Ok(()));
+    }
+}
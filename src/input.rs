@@ -1,47 +1,395 @@
 use std::error::Error;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicBool, Ordering};
 
-use crate::model::Input;
+use crate::history::History;
+use crate::model::{Input, Params};
 
-fn read_to_string(year: &str, day: &str, file: &str) -> std::io::Result<String> {
-    fs::read_to_string(
-        Path::new(".")
-            .join("inputs")
-            .join(year)
-            .join(day)
-            .join(file),
-    )
+mod encrypted;
+mod manifest;
+mod validate;
+
+pub use encrypted::Key;
+use manifest::{Entry, Manifest};
+pub use validate::{normalize, validate};
+
+static ROOT: OnceLock<PathBuf> = OnceLock::new();
+static NORMALIZE: AtomicBool = AtomicBool::new(false);
+static KEY: OnceLock<Key> = OnceLock::new();
+
+/// Sets the directory containing the inputs of all years. Without it, the closest `inputs`
+/// directory in the current directory or one of its ancestors is used.
+pub fn set_root(root: PathBuf) {
+    // the root is set once at startup, before any input is read
+    let _ = ROOT.set(root);
 }
 
-/// Reads the example files for the given year. If `part1_ex.txt` does not exist, this returns an error.
-pub fn get_example(year: &str, day: &str) -> Result<Input, Box<dyn Error>> {
-    let part1 = read_to_string(year, day, "part1_ex.txt")?;
-    let part2 = read_to_string(year, day, "part2_ex.txt").ok();
+/// Sets the key used to decrypt encrypted input files
+pub fn set_key(key: Key) {
+    // the key is set once at startup, before any input is read
+    let _ = KEY.set(key);
+}
 
-    let solution1 = read_to_string(year, day, "solution1_ex.txt").ok();
-    let solution2 = read_to_string(year, day, "solution2_ex.txt").ok();
+/// Sets whether inputs are normalized with [`normalize`] before they are passed to the solvers
+pub fn set_normalize(enabled: bool) {
+    NORMALIZE.store(enabled, Ordering::Relaxed);
+}
 
-    Ok(Input {
-        part1,
-        part2,
-        solution1,
-        solution2,
+/// The directory containing the inputs of all years
+pub fn root() -> &'static Path {
+    ROOT.get_or_init(|| {
+        std::env::current_dir()
+            .ok()
+            .and_then(|dir| find_root(&dir))
+            .unwrap_or_else(|| PathBuf::from("inputs"))
     })
 }
 
-/// Reads the example files for the given year. If part1.txt does not exist, this returns an error.
-pub fn get_problem(year: &str, day: &str) -> Result<Input, Box<dyn Error>> {
-    let part1 = read_to_string(year, day, "part1.txt")?;
-    let part2 = read_to_string(year, day, "part2.txt").ok();
+/// Searches `start` and its ancestors for an `inputs` directory
+fn find_root(start: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .map(|dir| dir.join("inputs"))
+        .find(|dir| dir.is_dir())
+}
+
+/// The directory containing the inputs of a day
+pub fn day_dir(year: &str, day: &str) -> PathBuf {
+    root().join(year).join(day)
+}
+
+/// Whether any day has a real input, plain or encrypted. This is false if the inputs directory
+/// is missing, empty or not readable, e.g. because the inputs submodule is not initialised.
+pub fn has_real_inputs() -> bool {
+    has_real_inputs_in(root())
+}
+
+fn has_real_inputs_in(root: &Path) -> bool {
+    let Ok(years) = fs::read_dir(root) else {
+        return false;
+    };
+    years
+        .flatten()
+        .filter_map(|year| fs::read_dir(year.path()).ok())
+        .flatten()
+        .flatten()
+        .any(|day| {
+            let path = day.path().join("part1.txt");
+            path.is_file() || encrypted_path(&path).is_file()
+        })
+}
+
+/// The path of the encrypted version of a file
+pub(crate) fn encrypted_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(format!(".{}", encrypted::EXTENSION));
+    path.with_file_name(name)
+}
+
+/// Reads a file of a day. If the file does not exist but an encrypted version of it does, the
+/// encrypted version is decrypted with the key.
+fn read_to_string(year: &str, day: &str, file: &str) -> std::io::Result<String> {
+    let path = day_dir(year, day).join(file);
+    let with_path = |path: &Path, e: String| format!("{}: {e}", path.display());
+    match fs::read_to_string(&path) {
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound && encrypted_path(&path).is_file() => {
+            let encrypted = encrypted_path(&path);
+            read_encrypted(&encrypted, KEY.get())
+                .map_err(|e| std::io::Error::new(e.kind(), with_path(&encrypted, e.to_string())))
+        }
+        result => {
+            result.map_err(|e| std::io::Error::new(e.kind(), with_path(&path, e.to_string())))
+        }
+    }
+}
+
+/// Decrypts an encrypted file. Failing to decrypt it is never reported as a missing file, so the
+/// input counts as errored rather than missing.
+fn read_encrypted(path: &Path, key: Option<&Key>) -> std::io::Result<String> {
+    let invalid = |e: String| std::io::Error::new(std::io::ErrorKind::InvalidData, e);
+    let key =
+        key.ok_or_else(|| invalid("the file is encrypted, but no key file was given".into()))?;
+    let contents = fs::read(path)?;
+    String::from_utf8(key.decrypt(&contents).map_err(invalid)?).map_err(|e| invalid(e.to_string()))
+}
 
-    let solution1 = read_to_string(year, day, "solution1.txt").ok();
-    let solution2 = read_to_string(year, day, "solution2.txt").ok();
+/// Encrypts the real inputs of a day with the key and returns the encrypted files that were
+/// written. The plain files are kept, they should be ignored by git.
+pub fn encrypt_inputs(year: &str, day: &str, key: &Key) -> std::io::Result<Vec<PathBuf>> {
+    let mut written = vec![];
+    for file in ["part1.txt", "part2.txt"] {
+        let path = day_dir(year, day).join(file);
+        let plaintext = match fs::read(&path) {
+            Ok(plaintext) => plaintext,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(e),
+        };
+        let encrypted = encrypted_path(&path);
+        fs::write(&encrypted, key.encrypt(&plaintext))?;
+        written.push(encrypted);
+    }
+    Ok(written)
+}
+
+/// Prints a warning for every problem [`validate`] finds in an input and normalizes the input if
+/// that is enabled. `source` names the input in the warnings.
+pub fn check(source: &Path, text: String) -> String {
+    let normalized = NORMALIZE.load(Ordering::Relaxed);
+    for problem in validate(&text) {
+        let note = if normalized && problem.is_normalized() {
+            " (normalized)"
+        } else {
+            ""
+        };
+        eprintln!("warning: {}: {problem}{note}", source.display());
+    }
+    if normalized { normalize(&text) } else { text }
+}
+
+fn read_checked(year: &str, day: &str, file: &str) -> std::io::Result<String> {
+    let text = read_to_string(year, day, file)?;
+    Ok(check(&day_dir(year, day).join(file), text))
+}
+
+/// How solution files are compared against answers
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SolutionMode {
+    /// Line endings are unified, trailing whitespace is removed from every line and blank lines
+    /// around the solution are dropped
+    #[default]
+    Normalized,
+    /// The solution is used byte for byte
+    Exact,
+}
+
+fn apply_mode(solution: String, mode: SolutionMode) -> String {
+    match mode {
+        SolutionMode::Normalized => normalize_solution(&solution),
+        SolutionMode::Exact => solution,
+    }
+}
+
+fn normalize_solution(solution: &str) -> String {
+    let lines: Vec<&str> = solution.lines().map(str::trim_end).collect();
+    let first = lines.iter().position(|line| !line.is_empty());
+    let last = lines.iter().rposition(|line| !line.is_empty());
+    match (first, last) {
+        (Some(first), Some(last)) if first == last => lines[first].trim_start().to_string(),
+        (Some(first), Some(last)) => lines[first..=last].join("\n"),
+        _ => String::new(),
+    }
+}
+
+/// Writes an accepted answer to the solution file of a part and returns the path of the file
+pub fn save_solution(year: &str, day: &str, part: u8, answer: &str) -> std::io::Result<PathBuf> {
+    let dir = day_dir(year, day);
+    fs::create_dir_all(&dir)?;
+    let path = dir.join(format!("solution{part}.txt"));
+    fs::write(&path, format!("{answer}\n"))?;
+    Ok(path)
+}
+
+/// Reads the `manifest.toml` of the given day, days without a manifest get an empty one
+fn read_manifest(year: &str, day: &str) -> Result<Manifest, Box<dyn Error>> {
+    match read_to_string(year, day, "manifest.toml") {
+        Ok(text) => Manifest::parse(&text).map_err(|e| {
+            let path = day_dir(year, day).join("manifest.toml");
+            format!("{}: {e}", path.display()).into()
+        }),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Manifest::default()),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// An example whose files could not be read
+#[derive(Debug)]
+pub struct UnreadableExample {
+    /// The label the input of the example would have had
+    pub label: String,
+    pub error: std::io::Error,
+}
+
+/// Reads the example files for the given day. The example in `part1_ex.txt` is followed by the
+/// numbered examples `part1_ex1.txt`, `part1_ex2.txt`, ... in ascending order, each with its own
+/// optional `part2_exN.txt` and `solution1_exN.txt`/`solution2_exN.txt`. Examples that are only
+/// declared in the manifest come last. Every example that cannot be read is returned as an
+/// [`UnreadableExample`], including `part1_ex.txt` if there are no examples at all. Only failing to
+/// read the manifest returns an error.
+pub fn get_examples(
+    year: &str,
+    day: &str,
+    mode: SolutionMode,
+) -> Result<Vec<Result<Input, UnreadableExample>>, Box<dyn Error>> {
+    let manifest = read_manifest(year, day)?;
+    let mut names = example_numbers(year, day);
+    for (name, _) in &manifest.examples {
+        if !name.is_empty() && !names.contains(name) {
+            names.push(name.clone());
+        }
+    }
+
+    let mut examples = vec![];
+    let read = |name: &str| {
+        let entry = manifest.example(name);
+        read_input(
+            year,
+            day,
+            &format!("_ex{name}"),
+            mode,
+            entry,
+            &manifest.params,
+        )
+        .map_err(|error| UnreadableExample {
+            label: format!("ex{name}"),
+            error,
+        })
+    };
+    // the unnumbered example is optional if there are numbered ones
+    match read("") {
+        Err(_) if !names.is_empty() => {}
+        example => examples.push(example),
+    }
+    for name in names {
+        examples.push(read(&name));
+    }
+    Ok(examples)
+}
+
+/// The numbers of the numbered examples in ascending order, found by their `part1_exN.txt` files.
+/// The numbers are kept as they are written in the file names, e.g. `02` for `part1_ex02.txt`.
+fn example_numbers(year: &str, day: &str) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(day_dir(year, day)) else {
+        return vec![];
+    };
+    let mut numbers: Vec<(u32, String)> = entries
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name();
+            let number = name
+                .to_str()?
+                .strip_prefix("part1_ex")?
+                .strip_suffix(".txt")?;
+            Some((number.parse().ok()?, number.to_string()))
+        })
+        .collect();
+    numbers.sort_unstable();
+    numbers.into_iter().map(|(_, number)| number).collect()
+}
+
+/// Reads the input and solutions whose file names end in `suffix`, e.g. `part1{suffix}.txt`.
+/// Files and solutions declared in the manifest entry take precedence.
+fn read_input(
+    year: &str,
+    day: &str,
+    suffix: &str,
+    mode: SolutionMode,
+    entry: Option<&Entry>,
+    params: &Params,
+) -> std::io::Result<Input> {
+    let file = |declared: Option<&String>, default: String| declared.cloned().unwrap_or(default);
+    let solution = |declared: Option<&String>, default: String| {
+        let solution = match declared {
+            Some(solution) => Some(solution.clone()),
+            None => optional(read_to_string(year, day, &default))?,
+        };
+        Ok::<_, std::io::Error>(solution.map(|solution| apply_mode(solution, mode)))
+    };
+    let default = Entry::default();
+    let entry = entry.unwrap_or(&default);
+
+    let part1 = read_checked(
+        year,
+        day,
+        &file(entry.part1.as_ref(), format!("part1{suffix}.txt")),
+    )?;
+    let part2 = optional(read_checked(
+        year,
+        day,
+        &file(entry.part2.as_ref(), format!("part2{suffix}.txt")),
+    ))?;
 
     Ok(Input {
+        label: suffix.trim_start_matches('_').to_string(),
         part1,
         part2,
-        solution1,
-        solution2,
+        solution1: solution(entry.solution1.as_ref(), format!("solution1{suffix}.txt"))?,
+        solution2: solution(entry.solution2.as_ref(), format!("solution2{suffix}.txt"))?,
+        params: manifest::merge_params(params, &entry.params),
+        history: History::default(),
     })
 }
+
+/// Turns a file that does not exist into `None`, other errors, e.g. failing to decrypt, are kept
+fn optional(result: std::io::Result<String>) -> std::io::Result<Option<String>> {
+    match result {
+        Ok(text) => Ok(Some(text)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Reads the input files and the history of rejected answers for the given day. If part1.txt does
+/// not exist, this returns an error. A history that cannot be read is ignored with a warning.
+pub fn get_problem(year: &str, day: &str, mode: SolutionMode) -> Result<Input, Box<dyn Error>> {
+    let manifest = read_manifest(year, day)?;
+    let mut input = read_input(year, day, "", mode, Some(&manifest.input), &manifest.params)?;
+    // a broken history only means answers are not checked against it, the input still runs
+    input.history = History::load(year, day).unwrap_or_else(|e| {
+        eprintln!("warning: ignoring the history of rejected answers, {e}");
+        History::default()
+    });
+    Ok(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize() {
+        assert_eq!(normalize_solution("42\n"), "42");
+        assert_eq!(normalize_solution(" 42 \r\n\r\n"), "42");
+        assert_eq!(normalize_solution(""), "");
+        assert_eq!(normalize_solution("\n#..# \r\n #  #\r\n\n"), "#..#\n #  #");
+    }
+
+    #[test]
+    fn finds_root_in_ancestors() {
+        let base = std::env::temp_dir().join(format!("aoc-rust-root-{}", std::process::id()));
+        let nested = base.join("target").join("debug");
+        fs::create_dir_all(&nested).unwrap();
+        fs::create_dir_all(base.join("inputs")).unwrap();
+
+        assert_eq!(find_root(&nested), Some(base.join("inputs")));
+        assert_eq!(find_root(&base), Some(base.join("inputs")));
+        fs::remove_dir_all(&base).unwrap();
+    }
+
+    #[test]
+    fn encrypted_files_fail_without_the_key() {
+        let path = std::env::temp_dir().join(format!("aoc-rust-enc-{}", std::process::id()));
+        let key = Key::generate();
+        fs::write(&path, key.encrypt(b"1\n2\n")).unwrap();
+
+        assert_eq!(read_encrypted(&path, Some(&key)).unwrap(), "1\n2\n");
+        for key in [None, Some(&Key::generate())] {
+            let error = read_encrypted(&path, key).unwrap_err();
+            assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+        }
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn detects_real_inputs() {
+        let base = std::env::temp_dir().join(format!("aoc-rust-real-{}", std::process::id()));
+        let day = base.join("2025").join("day01");
+        fs::create_dir_all(&day).unwrap();
+        assert!(!has_real_inputs_in(&base.join("missing")));
+        fs::write(day.join("part1_ex.txt"), "1").unwrap();
+        assert!(!has_real_inputs_in(&base));
+        fs::write(day.join("part1.txt.enc"), "1").unwrap();
+        assert!(has_real_inputs_in(&base));
+        fs::remove_dir_all(&base).unwrap();
+    }
+}
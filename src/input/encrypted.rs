@@ -0,0 +1,101 @@
+use std::fs;
+use std::path::Path;
+
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{ChaCha20Poly1305, Nonce};
+
+/// The extension of encrypted files, `part1.txt` is stored encrypted as `part1.txt.enc`
+pub const EXTENSION: &str = "enc";
+
+const NONCE_LEN: usize = 12;
+
+/// A symmetric key for ChaCha20-Poly1305, stored in key files as 64 hex digits
+#[derive(Clone)]
+pub struct Key([u8; 32]);
+
+impl Key {
+    /// Creates a new random key
+    pub fn generate() -> Key {
+        Key(ChaCha20Poly1305::generate_key(&mut OsRng).into())
+    }
+
+    /// Reads a key from a key file
+    pub fn load(path: &Path) -> Result<Key, String> {
+        let text = fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()))?;
+        Key::parse(&text).map_err(|e| format!("{}: {e}", path.display()))
+    }
+
+    /// Writes the key to a new key file, existing files are never overwritten
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        let mut file = fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(path)?;
+        std::io::Write::write_all(&mut file, format!("{}\n", self.to_hex()).as_bytes())
+    }
+
+    fn parse(text: &str) -> Result<Key, String> {
+        let text = text.trim();
+        let error = || "expected a key of 64 hex digits".to_string();
+        if text.len() != 64 || !text.is_ascii() {
+            return Err(error());
+        }
+        let mut key = [0; 32];
+        for (i, byte) in key.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&text[2 * i..2 * i + 2], 16).map_err(|_| error())?;
+        }
+        Ok(Key(key))
+    }
+
+    fn to_hex(&self) -> String {
+        self.0.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    fn cipher(&self) -> ChaCha20Poly1305 {
+        ChaCha20Poly1305::new(&self.0.into())
+    }
+
+    /// Encrypts a file's contents, the random nonce is stored in front of the ciphertext
+    pub fn encrypt(&self, plaintext: &[u8]) -> Vec<u8> {
+        let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+        // encrypting only fails for plaintexts of more than 256 GiB
+        let ciphertext = self.cipher().encrypt(&nonce, plaintext).unwrap();
+        [nonce.as_slice(), &ciphertext].concat()
+    }
+
+    /// Decrypts the contents of an encrypted file, failing if it was encrypted with another key
+    /// or has been modified
+    pub fn decrypt(&self, contents: &[u8]) -> Result<Vec<u8>, String> {
+        if contents.len() < NONCE_LEN {
+            return Err("the file is too short to be encrypted".to_string());
+        }
+        let (nonce, ciphertext) = contents.split_at(NONCE_LEN);
+        self.cipher()
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| "the file was encrypted with another key or is corrupted".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_keys() {
+        let hex = "00ff".repeat(16);
+        let key = Key::parse(&format!("{hex}\n")).unwrap();
+        assert_eq!(key.to_hex(), hex);
+        assert!(Key::parse("00ff").is_err());
+        assert!(Key::parse(&"zz".repeat(32)).is_err());
+    }
+
+    #[test]
+    fn decrypts_what_was_encrypted() {
+        let key = Key::generate();
+        let encrypted = key.encrypt(b"1\n2\n3\n");
+        assert_ne!(&encrypted[NONCE_LEN..], b"1\n2\n3\n");
+        assert_eq!(key.decrypt(&encrypted), Ok(b"1\n2\n3\n".to_vec()));
+        assert!(Key::generate().decrypt(&encrypted).is_err());
+        assert!(key.decrypt(&encrypted[..NONCE_LEN - 1]).is_err());
+    }
+}
@@ -0,0 +1,230 @@
+use crate::model::Params;
+
+/// The settings of one input in a manifest. Paths are relative to the directory of the day.
+#[derive(Debug, Default, PartialEq)]
+pub struct Entry {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+    pub solution1: Option<String>,
+    pub solution2: Option<String>,
+    pub params: Params,
+}
+
+/// An optional `manifest.toml` in the directory of a day, written in a small subset of TOML:
+///
+/// ```toml
+/// # parameters of every input
+/// [params]
+/// connections = 1000
+///
+/// # the real input
+/// [input]
+/// solution1 = 123
+///
+/// # the example in part1_ex.txt
+/// [example]
+/// connections = 10
+///
+/// # an example that only exists in the manifest
+/// [example.small]
+/// part1 = "small.txt"
+/// solution1 = "#.#\n.#."
+/// ```
+///
+/// The keys `part1` and `part2` name input files, `solution1` and `solution2` are the expected
+/// answers. All other keys are parameters that solvers can read.
+#[derive(Debug, Default, PartialEq)]
+pub struct Manifest {
+    pub params: Params,
+    pub input: Entry,
+    /// The examples by name, in the order they appear. The unnamed `[example]` has an empty name.
+    pub examples: Vec<(String, Entry)>,
+}
+
+impl Manifest {
+    pub fn parse(text: &str) -> Result<Manifest, String> {
+        let mut manifest = Manifest::default();
+        let mut section: Option<String> = None;
+
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let error = |message: &str| format!("line {}: {message}", i + 1);
+
+            if let Some(name) = line.strip_prefix('[') {
+                let name = name
+                    .strip_suffix(']')
+                    .ok_or_else(|| error("expected ']'"))?
+                    .trim();
+                if name != "params" && name != "input" && example_name(name).is_none() {
+                    return Err(error(&format!("unknown section [{name}]")));
+                }
+                if let Some(name) = example_name(name) {
+                    if manifest.examples.iter().any(|(n, _)| n == name) {
+                        return Err(error(&format!("duplicate section {line}")));
+                    }
+                    manifest.examples.push((name.to_string(), Entry::default()));
+                }
+                section = Some(name.to_string());
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| error("expected 'key = value'"))?;
+            let key = key.trim();
+            let value = parse_value(value.trim()).map_err(|e| error(&e))?;
+
+            match section.as_deref() {
+                None => return Err(error("expected a section before the first key")),
+                Some("params") => {
+                    manifest.params.insert(key.to_string(), value);
+                }
+                Some("input") => manifest.input.set(key, value),
+                Some(name) => {
+                    // the section was added to the examples when its header was parsed
+                    let name = example_name(name).unwrap();
+                    let (_, entry) = manifest
+                        .examples
+                        .iter_mut()
+                        .find(|(n, _)| n == name)
+                        .unwrap();
+                    entry.set(key, value);
+                }
+            }
+        }
+        Ok(manifest)
+    }
+
+    /// Returns the example with the given name, the unnamed example has an empty name
+    pub fn example(&self, name: &str) -> Option<&Entry> {
+        self.examples
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, entry)| entry)
+    }
+}
+
+impl Entry {
+    fn set(&mut self, key: &str, value: String) {
+        match key {
+            "part1" => self.part1 = Some(value),
+            "part2" => self.part2 = Some(value),
+            "solution1" => self.solution1 = Some(value),
+            "solution2" => self.solution2 = Some(value),
+            _ => {
+                self.params.insert(key.to_string(), value);
+            }
+        }
+    }
+}
+
+/// Returns the name of an example section, i.e. `""` for `example` and `"2"` for `example.2`
+fn example_name(section: &str) -> Option<&str> {
+    match section.strip_prefix("example")? {
+        "" => Some(""),
+        rest => rest.strip_prefix('.').filter(|name| !name.is_empty()),
+    }
+}
+
+/// Parses a quoted string with escapes or a bare value that ends at a comment
+fn parse_value(value: &str) -> Result<String, String> {
+    let Some(quoted) = value.strip_prefix('"') else {
+        let bare = value.split_once('#').map_or(value, |(v, _)| v).trim();
+        return Ok(bare.to_string());
+    };
+
+    let mut out = String::new();
+    let mut chars = quoted.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => {
+                let rest = chars.as_str().trim();
+                if !rest.is_empty() && !rest.starts_with('#') {
+                    return Err(format!("unexpected '{rest}' after string"));
+                }
+                return Ok(out);
+            }
+            '\\' => match chars.next() {
+                Some('n') => out.push('\n'),
+                Some('t') => out.push('\t'),
+                Some('"') => out.push('"'),
+                Some('\\') => out.push('\\'),
+                other => return Err(format!("invalid escape '\\{}'", other.unwrap_or(' '))),
+            },
+            c => out.push(c),
+        }
+    }
+    Err("unterminated string".to_string())
+}
+
+/// Combines the parameters of the day with those of an input, the input takes precedence
+pub fn merge_params(day: &Params, input: &Params) -> Params {
+    let mut params: Params = day.clone();
+    params.extend(input.iter().map(|(k, v)| (k.clone(), v.clone())));
+    params
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    fn params(pairs: &[(&str, &str)]) -> Params {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect::<HashMap<_, _>>()
+    }
+
+    #[test]
+    fn parse_manifest() {
+        let manifest = Manifest::parse(
+            "# comment\n\
+             [params]\n\
+             connections = 1000 # trailing comment\n\
+             \n\
+             [input]\n\
+             solution1 = 123\n\
+             \n\
+             [example]\n\
+             connections = 10\n\
+             \n\
+             [example.small]\n\
+             part1 = \"small.txt\"\n\
+             solution1 = \"#.\\n.#\"\n",
+        )
+        .unwrap();
+
+        assert_eq!(manifest.params, params(&[("connections", "1000")]));
+        assert_eq!(manifest.input.solution1.as_deref(), Some("123"));
+        assert_eq!(
+            manifest.example("").unwrap().params,
+            params(&[("connections", "10")])
+        );
+        let small = manifest.example("small").unwrap();
+        assert_eq!(small.part1.as_deref(), Some("small.txt"));
+        assert_eq!(small.solution1.as_deref(), Some("#.\n.#"));
+        assert_eq!(manifest.example("2"), None);
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert!(Manifest::parse("a = 1").is_err());
+        assert!(Manifest::parse("[other]").is_err());
+        assert!(Manifest::parse("[example.]").is_err());
+        assert!(Manifest::parse("[params\na = 1").is_err());
+        assert!(Manifest::parse("[params]\na").is_err());
+        assert!(Manifest::parse("[params]\na = \"open").is_err());
+        assert!(Manifest::parse("[example]\n[example]").is_err());
+    }
+
+    #[test]
+    fn merge() {
+        let merged = merge_params(&params(&[("a", "1"), ("b", "2")]), &params(&[("b", "3")]));
+        assert_eq!(merged, params(&[("a", "1"), ("b", "3")]));
+    }
+}
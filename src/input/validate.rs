@@ -0,0 +1,251 @@
+use std::fmt;
+
+/// Something about an input that solvers do not expect. Line numbers start at one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Problem {
+    ByteOrderMark,
+    CrlfLineEndings {
+        lines: usize,
+    },
+    TrailingBlankLines {
+        count: usize,
+    },
+    Tabs {
+        line: usize,
+    },
+    NonAscii {
+        line: usize,
+        character: char,
+    },
+    /// A row of a grid has a different length than the rows around it
+    RaggedRow {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl Problem {
+    /// Whether [`normalize`] removes the problem
+    pub fn is_normalized(&self) -> bool {
+        matches!(
+            self,
+            Problem::ByteOrderMark
+                | Problem::CrlfLineEndings { .. }
+                | Problem::TrailingBlankLines { .. }
+        )
+    }
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Problem::ByteOrderMark => write!(f, "starts with a byte order mark"),
+            Problem::CrlfLineEndings { lines } => write!(f, "{lines} lines end with CRLF"),
+            Problem::TrailingBlankLines { count: 1 } => write!(f, "ends with a blank line"),
+            Problem::TrailingBlankLines { count } => write!(f, "ends with {count} blank lines"),
+            Problem::Tabs { line } => write!(f, "line {line} contains a tab"),
+            Problem::NonAscii { line, character } => {
+                write!(
+                    f,
+                    "line {line} contains the non-ASCII character {character:?}"
+                )
+            }
+            Problem::RaggedRow {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {line} has {found} characters, the other rows of the grid have {expected}"
+            ),
+        }
+    }
+}
+
+/// Checks an input for problems, tabs, non-ASCII characters and ragged rows are only reported
+/// for the first line they occur in
+pub fn validate(text: &str) -> Vec<Problem> {
+    let mut problems = vec![];
+    if text.starts_with('\u{feff}') {
+        problems.push(Problem::ByteOrderMark);
+    }
+    let crlf = text.matches("\r\n").count();
+    if crlf > 0 {
+        problems.push(Problem::CrlfLineEndings { lines: crlf });
+    }
+
+    let text = text.trim_start_matches('\u{feff}');
+    let lines: Vec<&str> = text.lines().collect();
+    let blank = lines
+        .iter()
+        .rev()
+        .take_while(|line| line.trim().is_empty())
+        .count();
+    if blank > 0 {
+        problems.push(Problem::TrailingBlankLines { count: blank });
+    }
+    if let Some(line) = lines.iter().position(|line| line.contains('\t')) {
+        problems.push(Problem::Tabs { line: line + 1 });
+    }
+    if let Some((line, character)) = lines
+        .iter()
+        .enumerate()
+        .find_map(|(i, line)| Some((i + 1, line.chars().find(|c| !c.is_ascii())?)))
+    {
+        problems.push(Problem::NonAscii { line, character });
+    }
+    problems.extend(ragged_row(&lines));
+    problems
+}
+
+/// The narrowest row of a block of lines that is taken as a grid
+const MIN_GRID_WIDTH: usize = 5;
+
+/// Finds the first row of a grid that is longer or shorter than the other rows. A block of at
+/// least three lines between blank lines is taken as a grid if it has no whitespace or commas and
+/// its most common row length is at least [`MIN_GRID_WIDTH`]. That length is the width of the
+/// grid, and at most one row in ten, or a single row in small blocks, may deviate from it.
+fn ragged_row(lines: &[&str]) -> Option<Problem> {
+    let mut start = 0;
+    for block in lines.split(|line| line.trim().is_empty()) {
+        let first = start;
+        start += block.len() + 1;
+        if block.len() < 3
+            || block
+                .iter()
+                .any(|line| line.contains(|c: char| c.is_whitespace() || c == ','))
+        {
+            continue;
+        }
+
+        let lengths: Vec<usize> = block.iter().map(|line| line.chars().count()).collect();
+        let mut counts: Vec<(usize, usize)> = vec![];
+        for &length in &lengths {
+            match counts.iter_mut().find(|(l, _)| *l == length) {
+                Some((_, count)) => *count += 1,
+                None => counts.push((length, 1)),
+            }
+        }
+        // ties go to the length that occurs first
+        let (expected, matching) = counts
+            .iter()
+            .copied()
+            .reduce(|best, next| if next.1 > best.1 { next } else { best })?;
+        let deviating = block.len() - matching;
+        if expected >= MIN_GRID_WIDTH && deviating > 0 && deviating <= (block.len() / 10).max(1) {
+            let (offset, found) = lengths
+                .into_iter()
+                .enumerate()
+                .find(|(_, length)| *length != expected)?;
+            return Some(Problem::RaggedRow {
+                line: first + offset + 1,
+                expected,
+                found,
+            });
+        }
+    }
+    None
+}
+
+/// Removes a byte order mark, converts CRLF line endings and drops trailing blank lines
+pub fn normalize(text: &str) -> String {
+    let text = text.trim_start_matches('\u{feff}').replace("\r\n", "\n");
+    let trimmed = text.trim_end();
+    if trimmed.is_empty() {
+        return String::new();
+    }
+    let last_line = trimmed.rfind('\n').map_or(0, |i| i + 1);
+    // whitespace at the end of the last line is kept, only the blank lines after it are removed
+    let end = text[last_line..]
+        .find('\n')
+        .map_or(text.len(), |i| last_line + i + 1);
+    text[..end].to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_problems() {
+        assert_eq!(validate("#.#\n...\n"), []);
+        assert_eq!(
+            validate("\u{feff}a\r\nb\r\n\r\n"),
+            [
+                Problem::ByteOrderMark,
+                Problem::CrlfLineEndings { lines: 3 },
+                Problem::TrailingBlankLines { count: 1 },
+            ]
+        );
+        assert_eq!(
+            validate("a\tb\nc\n\u{e9}\n"),
+            [
+                Problem::Tabs { line: 1 },
+                Problem::NonAscii {
+                    line: 3,
+                    character: '\u{e9}'
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn detects_ragged_rows() {
+        let grid = "..........\n".repeat(9) + ".........\n" + &"..........\n".repeat(10);
+        assert_eq!(
+            validate(&grid),
+            [Problem::RaggedRow {
+                line: 10,
+                expected: 10,
+                found: 9
+            }]
+        );
+        assert_eq!(
+            validate(&format!("1 2\n\n{grid}"))[0],
+            Problem::RaggedRow {
+                line: 12,
+                expected: 10,
+                found: 9
+            }
+        );
+        // lists of values are not grids
+        assert_eq!(validate("L68\nR5\nL30\nR48\n"), []);
+        assert_eq!(validate("12,3\n1,45\n"), []);
+    }
+
+    #[test]
+    fn detects_truncated_last_row() {
+        let grid = "#.#.#.#.#.\n".repeat(19) + "#.#.#\n";
+        assert_eq!(
+            validate(&grid),
+            [Problem::RaggedRow {
+                line: 20,
+                expected: 10,
+                found: 5
+            }]
+        );
+    }
+
+    #[test]
+    fn detects_short_row_in_small_grid() {
+        assert_eq!(
+            validate("..#..\n.#.#.\n#...\n.#.#.\n..#..\n"),
+            [Problem::RaggedRow {
+                line: 3,
+                expected: 5,
+                found: 4
+            }]
+        );
+        // more than one deviating row in a small block is not a grid
+        assert_eq!(validate("..#..\n.#.\n#...\n.#.#.\n..#..\n"), []);
+    }
+
+    #[test]
+    fn normalizes() {
+        assert_eq!(normalize("\u{feff}a\r\nb \r\n\r\n\n"), "a\nb \n");
+        assert_eq!(normalize("a\nb"), "a\nb");
+        assert_eq!(normalize("a\n"), "a\n");
+        assert_eq!(normalize("\n\n"), "");
+    }
+}
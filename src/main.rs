@@ -1,200 +1,691 @@
+mod bench;
+mod client;
+mod history;
 mod input;
 mod model;
+mod output;
+mod runner;
+mod scaffold;
+mod select;
 mod solvers;
 mod utils;
+mod watch;
 
-use std::time::Instant;
+use std::io::Read;
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::thread;
+use std::time::Duration;
 
-use clap::Parser;
-use comfy_table::{Cell, Color, Table};
+use clap::{Parser, Subcommand};
 
-use model::Outcome;
+use client::Verdict;
+use history::{Hint, History, Rejection};
+use input::SolutionMode;
+use model::{Input, Outcome, Status};
+use output::Format;
+use runner::Task;
+use select::{DayRange, Selection};
 
 /// Solver for advent of code written in rust 🦀
 #[derive(Parser, Debug)]
 #[command(version, about, long_about = None)]
 struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
     /// The year to run
-    #[arg(short, long)]
+    #[arg(short, long, global = true)]
     year: Option<u32>,
 
-    /// The day to run, only used with --year
-    #[arg(short, long)]
+    /// The day to run in the given year
+    #[arg(short, long, global = true, requires = "year")]
     day: Option<u32>,
 
+    /// The years and days to run, e.g. 2025:1-5,2024:10 or 2019
+    #[arg(
+        long,
+        value_name = "YEAR[:DAYS]",
+        value_delimiter = ',',
+        conflicts_with = "year",
+        global = true
+    )]
+    days: Vec<DayRange>,
+
+    /// Run only this part
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Solve this file instead of the inputs of the day, use - to read from stdin
+    #[arg(long, value_name = "PATH|-", requires_all = ["year", "day"])]
+    input: Option<PathBuf>,
+
+    /// The expected answer of the part solved with --input
+    #[arg(long, value_name = "ANSWER", requires_all = ["input", "part"])]
+    expect: Option<String>,
+
+    /// Select only days with this tag, e.g. grid or graph
+    #[arg(long, global = true)]
+    tag: Option<String>,
+
     /// Run only the last day of the latest year
-    #[arg(short, long, name = "last")]
+    #[arg(short, long, name = "last", conflicts_with_all = ["year", "days"])]
     last_only: bool,
 
     /// Run only the examples
     #[arg(short, long, name = "examples")]
     examples_only: bool,
+
+    /// Run only the real inputs
+    #[arg(short, long, name = "real", conflicts_with = "examples")]
+    real_only: bool,
+
+    /// The directory with the inputs of all years, defaults to the closest `inputs` directory in
+    /// the current directory or its ancestors
+    #[arg(long, value_name = "DIR", env = "AOC_INPUTS")]
+    inputs: Option<PathBuf>,
+
+    /// Remove byte order marks, CRLF line endings and trailing blank lines from inputs before
+    /// they are solved
+    #[arg(long)]
+    normalize_input: bool,
+
+    /// Compare solution files byte for byte instead of ignoring whitespace and line endings
+    #[arg(long)]
+    exact: bool,
+
+    /// The number of days to run in parallel, defaults to the number of available cores
+    #[arg(short, long)]
+    jobs: Option<NonZeroUsize>,
+
+    /// Run one day at a time so timings are measured in isolation
+    #[arg(long, conflicts_with = "jobs")]
+    serial: bool,
+
+    /// Abandon parts that take longer than this many seconds and report them as errored
+    #[arg(short, long, value_name = "SECONDS", value_parser = parse_timeout)]
+    timeout: Option<f64>,
+
+    /// The format used to print the results
+    #[arg(short, long, value_enum, default_value_t = Format::Table)]
+    format: Format,
+
+    /// Print a compact summary and exit with an error if any part is incorrect or errored
+    #[arg(long)]
+    check: bool,
+
+    /// Also fail the check on parts without a known solution
+    #[arg(long, requires = "check")]
+    strict: bool,
+
+    /// Download the missing inputs and examples of the selected days before running them
+    #[arg(long)]
+    fetch: bool,
+
+    /// Benchmark the real inputs by solving each part this many times
+    #[arg(long, value_name = "ITERATIONS", value_parser = clap::value_parser!(u32).range(1..))]
+    bench: Option<u32>,
+
+    /// The number of untimed iterations before benchmarking
+    #[arg(long, default_value_t = 3, requires = "bench")]
+    warmup: u32,
+
+    /// Write the benchmark medians to this file
+    #[arg(long, value_name = "FILE", requires = "bench")]
+    save_baseline: Option<PathBuf>,
+
+    /// Compare the benchmark medians against this file and fail on regressions
+    #[arg(long, value_name = "FILE", requires = "bench")]
+    baseline: Option<PathBuf>,
+
+    /// The slowdown in percent above which a part counts as a regression
+    #[arg(long, default_value_t = 10.0, requires = "baseline")]
+    threshold: f64,
+
+    /// The file with the key that encrypted inputs are decrypted with
+    #[arg(long, global = true, value_name = "FILE", env = "AOC_KEY_FILE")]
+    key_file: Option<PathBuf>,
+
+    /// The URL of the puzzle site that inputs are downloaded from and answers are submitted to
+    #[arg(long, global = true, env = "AOC_BASE_URL", default_value = client::DEFAULT_BASE_URL)]
+    base_url: String,
+
+    /// The session token used to authenticate with the puzzle site
+    #[arg(long, global = true, env = "AOC_SESSION", hide_env_values = true)]
+    session: Option<String>,
 }
 
-fn main() {
-    let args = Args::parse();
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Create the solver of a new day from a template and its input directory
+    New {
+        year: u32,
+        #[arg(value_parser = clap::value_parser!(u32).range(1..=25))]
+        day: u32,
+    },
+    /// List the selected days with their titles, tags and implemented parts
+    List,
+    /// Re-run the selected days, or the last day, whenever their sources or inputs change
+    Watch,
+    /// Solve a part of the selected day and submit the answer, accepted answers are saved
+    Submit {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+    },
+    /// Record an answer of the selected day that was rejected, e.g. when submitted by hand
+    Record {
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        #[arg(value_enum)]
+        hint: Hint,
+        answer: String,
+    },
+    /// Encrypt the real inputs of the selected days with the key file, which is created if it
+    /// does not exist. The plain inputs are kept and should be ignored by git.
+    Encrypt,
+}
 
-    let mut results: Vec<Outcome> = vec![];
+fn main() -> ExitCode {
+    let args = Args::parse();
 
-    if args.last_only {
-        run_last(args.examples_only, &mut results);
-    } else if let Some(year) = args.year.map(|y| y.to_string()) {
-        if let Some(day) = args.day.map(|d| format!("day{d:02}")) {
-            run_day_in_year(&year, &day, args.examples_only, &mut results);
-        } else {
-            run_year(&year, args.examples_only, &mut results);
+    if let Some(inputs) = &args.inputs {
+        if !inputs.is_dir() {
+            eprintln!("The inputs directory {} does not exist", inputs.display());
+            return ExitCode::FAILURE;
+        }
+        input::set_root(inputs.clone());
+    }
+    input::set_normalize(args.normalize_input);
+    if let Some(path) = &args.key_file
+        && !matches!(args.command, Some(Command::Encrypt))
+    {
+        match input::Key::load(path) {
+            Ok(key) => input::set_key(key),
+            Err(e) => {
+                eprintln!("Failed to load the key: {e}");
+                return ExitCode::FAILURE;
+            }
         }
-    } else {
-        run_all(args.examples_only, &mut results);
     }
 
-    if results.is_empty() {
-        println!("No days were run.");
-        return;
-    }
-
-    let mut table = Table::new();
-    table.set_header(vec![
-        "year",
-        "day",
-        "part",
-        "result",
-        "solution",
-        "correct",
-        "elapsed ms",
-    ]);
-    for outcome in &results {
-        table.add_row(vec![
-            Cell::new(outcome.year),
-            Cell::new(outcome.day),
-            Cell::new(outcome.part.as_str()),
-            Cell::new(outcome.result.as_str()),
-            Cell::new(outcome.solution.as_str()),
-            if outcome.correct {
-                Cell::new("yes").fg(Color::Green)
+    match args.command {
+        Some(Command::New { year, day }) => {
+            return match scaffold::new_day(year, day) {
+                Ok(()) => ExitCode::SUCCESS,
+                Err(e) => {
+                    eprintln!("Failed to create the day: {e}");
+                    ExitCode::FAILURE
+                }
+            };
+        }
+        Some(Command::List) => {
+            println!("{}", output::days(&select_days(&args)));
+            return ExitCode::SUCCESS;
+        }
+        Some(Command::Watch) => {
+            let days = if args.year.is_none() && args.days.is_empty() && args.tag.is_none() {
+                Selection {
+                    last_only: true,
+                    ..Selection::default()
+                }
+                .days()
             } else {
-                Cell::new("no").fg(Color::Red)
-            },
-            Cell::new(outcome.elapsed_ms.to_string()),
-        ]);
+                select_days(&args)
+            };
+            watch::watch(&days, &watch_args(&args));
+        }
+        Some(Command::Submit { part }) => return submit(&args, part),
+        Some(Command::Record {
+            part,
+            hint,
+            ref answer,
+        }) => return record(&args, part, hint, answer),
+        Some(Command::Encrypt) => return encrypt(&args),
+        None => {}
     }
-    println!("{table}");
-}
 
-fn run_year(year_arg: &str, example_only: bool, results: &mut Vec<Outcome>) {
-    for (year, days) in solvers::YEARS {
-        if year_arg == *year {
-            for day in *days {
-                run_day(year, day, true, results);
-                if !example_only {
-                    run_day(year, day, false, results);
+    if let Some(path) = &args.input {
+        return run_input(&args, path);
+    }
+
+    if args.fetch {
+        let missing: Vec<_> = select_days(&args)
+            .into_iter()
+            .filter(|(year, day)| client::is_missing(year, day.name))
+            .collect();
+        // the session is only needed if something has to be downloaded
+        if !missing.is_empty() {
+            let Some(client) = client(&args) else {
+                return ExitCode::FAILURE;
+            };
+            for (year, day) in missing {
+                match client.fetch_missing(year, day.name) {
+                    Ok(written) => {
+                        for path in written {
+                            eprintln!("Downloaded {}", path.display());
+                        }
+                    }
+                    Err(e) => eprintln!("Failed to download {year} {}: {e}", day.name),
                 }
             }
         }
     }
+
+    if let Some(iterations) = args.bench {
+        return run_bench(&args, iterations);
+    }
+
+    // not everyone has access to the inputs submodule, so only the examples are run without it
+    let real = !args.examples_only && input::has_real_inputs();
+    let fallback = !args.examples_only && !real;
+    if fallback {
+        eprintln!(
+            "No real inputs were found in {}, is the inputs submodule initialised? Running the examples only, days without inputs are skipped.",
+            input::root().display()
+        );
+    }
+
+    let mut tasks = vec![];
+    for (year, day) in select_days(&args) {
+        // the examples live in the submodule as well, without it there is nothing to run
+        if fallback && !input::day_dir(year, day.name).is_dir() {
+            continue;
+        }
+        if !args.real_only || !real {
+            tasks.push(Task {
+                year,
+                day,
+                example: true,
+            });
+        }
+        if real {
+            tasks.push(Task {
+                year,
+                day,
+                example: false,
+            });
+        }
+    }
+
+    let results: Vec<Outcome> = runner::run(&tasks, &runner_options(&args));
+
+    if args.check {
+        println!("{}", output::summary(&results, args.strict));
+        return if results.iter().any(|o| o.status.fails_check(args.strict)) {
+            ExitCode::FAILURE
+        } else {
+            ExitCode::SUCCESS
+        };
+    }
+
+    if results.is_empty() && args.format == Format::Table {
+        println!("No days were run.");
+        return ExitCode::SUCCESS;
+    }
+
+    println!("{}", output::render(args.format, &results));
+    ExitCode::SUCCESS
 }
 
-fn run_day_in_year(year_arg: &str, day_arg: &str, example_only: bool, results: &mut Vec<Outcome>) {
-    for (year, days) in solvers::YEARS {
-        if year_arg == *year {
-            for day in *days {
-                if day_arg == day.name {
-                    run_day(year, day, true, results);
-                    if !example_only {
-                        run_day(year, day, false, results);
-                    }
-                }
+fn runner_options(args: &Args) -> runner::Options {
+    let jobs = if args.serial {
+        1
+    } else {
+        args.jobs
+            .or_else(|| thread::available_parallelism().ok())
+            .map_or(1, NonZeroUsize::get)
+    };
+    runner::Options {
+        mode: if args.exact {
+            SolutionMode::Exact
+        } else {
+            SolutionMode::Normalized
+        },
+        jobs,
+        timeout: args.timeout.map(Duration::from_secs_f64),
+        part: args.part,
+    }
+}
+
+/// Solves the file given with --input and prints the answers, one per line if both parts run
+fn run_input(args: &Args, path: &Path) -> ExitCode {
+    let [(year, day)] = select_days(args)[..] else {
+        eprintln!("There is no solver for the given year and day");
+        return ExitCode::FAILURE;
+    };
+    let text = if path == Path::new("-") {
+        let mut text = String::new();
+        std::io::stdin().read_to_string(&mut text).map(|_| text)
+    } else {
+        std::fs::read_to_string(path)
+    };
+    let text = match text {
+        Ok(text) => input::check(path, text),
+        Err(e) => {
+            eprintln!("Failed to read {}: {e}", path.display());
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let expect = |part| args.expect.clone().filter(|_| args.part == Some(part));
+    let input = Input {
+        label: String::new(),
+        part1: text,
+        part2: None,
+        solution1: expect(1),
+        solution2: expect(2),
+        params: Default::default(),
+        history: History::default(),
+    };
+    let mut code = ExitCode::SUCCESS;
+    for outcome in runner::run_input(year, day, input, &runner_options(args)) {
+        let answer = match (&outcome.error, outcome.status) {
+            (Some(error), _) => error,
+            (None, Status::NotImplemented) => "not implemented",
+            (None, _) => &outcome.result,
+        };
+        if args.part.is_some() {
+            println!("{answer}");
+        } else {
+            println!("part {}: {answer}", outcome.part);
+        }
+
+        match outcome.status {
+            Status::Correct | Status::Unverified => {}
+            Status::Incorrect => {
+                eprintln!("expected {}", outcome.solution);
+                code = ExitCode::FAILURE;
             }
+            _ => code = ExitCode::FAILURE,
         }
     }
+    code
+}
+
+/// The arguments passed on to every run of watch mode, apart from the selected days
+fn watch_args(args: &Args) -> Vec<String> {
+    let root = input::root();
+    let mut out = vec![
+        "--inputs".to_string(),
+        root.canonicalize()
+            .unwrap_or_else(|_| root.to_path_buf())
+            .display()
+            .to_string(),
+    ];
+    if args.exact {
+        out.push("--exact".to_string());
+    }
+    if args.normalize_input {
+        out.push("--normalize-input".to_string());
+    }
+    if let Some(key_file) = &args.key_file {
+        // cargo runs in the crate directory, so relative paths would no longer resolve
+        let key_file = key_file.canonicalize().unwrap_or_else(|_| key_file.clone());
+        out.push(format!("--key-file={}", key_file.display()));
+    }
+    if let Some(part) = args.part {
+        out.push(format!("--part={part}"));
+    }
+    if let Some(timeout) = args.timeout {
+        out.push(format!("--timeout={timeout}"));
+    }
+    out
+}
+
+/// The client for the puzzle site, reports a missing session token and returns `None` without one
+fn client(args: &Args) -> Option<client::Client> {
+    let Some(session) = args.session.clone() else {
+        eprintln!("Set the session token with --session or AOC_SESSION");
+        return None;
+    };
+    Some(client::Client {
+        base_url: args.base_url.clone(),
+        session,
+        transport: Box::new(client::Curl),
+    })
 }
 
-fn run_last(example_only: bool, results: &mut Vec<Outcome>) {
-    if let Some(year) = solvers::YEARS.last() {
-        if let Some(day) = year.1.last() {
-            run_day(year.0, day, true, results);
-            if !example_only {
-                run_day(year.0, day, false, results);
+/// Solves a part of the selected day with the real input and submits the answer
+fn submit(args: &Args, part: u8) -> ExitCode {
+    let [(year, day)] = select_days(args)[..] else {
+        eprintln!("Select a single day to submit with --year and --day");
+        return ExitCode::FAILURE;
+    };
+    let Some(client) = client(args) else {
+        return ExitCode::FAILURE;
+    };
+
+    let task = Task {
+        year,
+        day,
+        example: false,
+    };
+    let options = runner::Options {
+        jobs: 1,
+        part: Some(part),
+        ..runner_options(args)
+    };
+    // there is exactly one outcome because a single part of a single input runs
+    let outcome = runner::run(&[task], &options).remove(0);
+    // running the day already warned about a history that cannot be read
+    let history = History::load(year, day.name).unwrap_or_default();
+    if matches!(outcome.status, Status::Unverified | Status::Incorrect)
+        && let Err(e) = history.check(part, &outcome.result)
+    {
+        eprintln!("Not submitting: {e}");
+        return ExitCode::FAILURE;
+    }
+    match outcome.status {
+        Status::Unverified => {}
+        Status::Correct => {
+            println!("Part {part} is already solved: {}", outcome.result);
+            return ExitCode::SUCCESS;
+        }
+        Status::Incorrect => {
+            eprintln!(
+                "The answer {} differs from the known solution {}",
+                outcome.result, outcome.solution
+            );
+            return ExitCode::FAILURE;
+        }
+        status => {
+            let error = outcome.error.unwrap_or_default();
+            eprintln!(
+                "Part {part} cannot be submitted, it is {}: {error}",
+                status.as_str()
+            );
+            return ExitCode::FAILURE;
+        }
+    }
+
+    println!(
+        "Submitting {} for {year} day {} part {part}",
+        outcome.result, outcome.day
+    );
+    let verdict = match client.submit(year, outcome.day, part, &outcome.result) {
+        Ok(verdict) => verdict,
+        Err(e) => {
+            eprintln!("Failed to submit the answer: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let hint = match verdict {
+        Verdict::Wrong => Some(Hint::Wrong),
+        Verdict::TooHigh => Some(Hint::High),
+        Verdict::TooLow => Some(Hint::Low),
+        _ => None,
+    };
+    if let Some(hint) = hint {
+        let rejection = Rejection {
+            part,
+            hint,
+            answer: outcome.result.clone(),
+        };
+        if let Err(e) = History::record(year, day.name, &rejection) {
+            eprintln!("Failed to record the rejected answer: {e}");
+        }
+    }
+    match &verdict {
+        Verdict::Correct => match input::save_solution(year, day.name, part, &outcome.result) {
+            Ok(path) => println!("Correct, saved the answer to {}", path.display()),
+            Err(e) => {
+                eprintln!("Correct, but the answer could not be saved: {e}");
+                return ExitCode::FAILURE;
             }
+        },
+        Verdict::Wrong => println!("Wrong answer"),
+        Verdict::TooHigh => println!("Wrong answer, it is too high"),
+        Verdict::TooLow => println!("Wrong answer, it is too low"),
+        Verdict::RateLimited(wait) => println!("Answered too recently, wait {wait}"),
+        Verdict::WrongLevel => println!("Part {part} is already solved or not unlocked yet"),
+        Verdict::Unknown(text) => println!("Unexpected response: {text}"),
+    }
+    if verdict == Verdict::Correct {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// Adds a rejected answer to the history of the selected day
+fn record(args: &Args, part: u8, hint: Hint, answer: &str) -> ExitCode {
+    let [(year, day)] = select_days(args)[..] else {
+        eprintln!("Select a single day to record with --year and --day");
+        return ExitCode::FAILURE;
+    };
+    let rejection = Rejection {
+        part,
+        hint,
+        answer: answer.trim().to_string(),
+    };
+    match History::record(year, day.name, &rejection) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Failed to record the rejected answer: {e}");
+            ExitCode::FAILURE
         }
     }
 }
 
-fn run_all(example_only: bool, results: &mut Vec<Outcome>) {
-    for (year, days) in solvers::YEARS {
-        for day in *days {
-            run_day(year, day, true, results);
-            if !example_only {
-                run_day(year, day, false, results);
+/// Encrypts the real inputs of the selected days, creating the key file if it does not exist
+fn encrypt(args: &Args) -> ExitCode {
+    let Some(path) = &args.key_file else {
+        eprintln!("Encrypting needs a key file, pass --key-file or set AOC_KEY_FILE");
+        return ExitCode::FAILURE;
+    };
+    let key = if path.exists() {
+        input::Key::load(path)
+    } else {
+        let key = input::Key::generate();
+        key.save(path)
+            .map(|()| {
+                eprintln!(
+                    "Created the key file {}, keep it out of git",
+                    path.display()
+                );
+                key
+            })
+            .map_err(|e| format!("{}: {e}", path.display()))
+    };
+    let key = match key {
+        Ok(key) => key,
+        Err(e) => {
+            eprintln!("Failed to load the key: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut code = ExitCode::SUCCESS;
+    for (year, day) in select_days(args) {
+        match input::encrypt_inputs(year, day.name, &key) {
+            Ok(written) => {
+                for path in written {
+                    eprintln!("Encrypted {}", path.display());
+                }
+            }
+            Err(e) => {
+                eprintln!("Failed to encrypt {year} {}: {e}", day.name);
+                code = ExitCode::FAILURE;
             }
         }
     }
+    code
 }
 
-fn run_day(year: &'static str, day: &solvers::Day, example: bool, results: &mut Vec<Outcome>) {
-    let Ok(input) = (if example {
-        input::get_example(year, day.name)
-    } else {
-        input::get_problem(year, day.name)
-    }) else {
-        if example {
-            eprintln!("Failed to get example input for {year} {}", day.name);
-        } else {
-            eprintln!("Failed to get input for {year} {}", day.name);
+fn run_bench(args: &Args, iterations: u32) -> ExitCode {
+    if !input::has_real_inputs() {
+        eprintln!(
+            "No real inputs were found in {}, is the inputs submodule initialised? Benchmarks need the real inputs.",
+            input::root().display()
+        );
+        return ExitCode::FAILURE;
+    }
+    let baseline = match args
+        .baseline
+        .as_deref()
+        .map(bench::load_baseline)
+        .transpose()
+    {
+        Ok(baseline) => baseline,
+        Err(e) => {
+            eprintln!("Failed to load baseline: {e}");
+            return ExitCode::FAILURE;
         }
-        return;
     };
 
-    if let Some((result, correct, elapsed_ms)) =
-        run_part(&input.part1, day.part1, input.solution1.as_deref())
+    let benchmarks = bench::run(
+        &select_days(args),
+        iterations as usize,
+        args.warmup as usize,
+        args.part,
+    );
+    println!(
+        "{}",
+        bench::render(&benchmarks, baseline.as_ref(), args.threshold)
+    );
+
+    if let Some(path) = &args.save_baseline
+        && let Err(e) = bench::save_baseline(path, &benchmarks)
     {
-        results.push(Outcome {
-            year,
-            // unwrapping here is safe because day names always start with "day"
-            day: day.name.strip_prefix("day").unwrap(),
-            part: if example { "1ex" } else { "1" }.to_string(),
-            result,
-            solution: input.solution1.unwrap_or_default(),
-            correct,
-            elapsed_ms,
-        });
+        eprintln!("Failed to save baseline: {e}");
+        return ExitCode::FAILURE;
     }
 
-    if let Some((result, correct, elapsed_ms)) = run_part(
-        input.part2.as_deref().unwrap_or(&input.part1),
-        day.part2,
-        input.solution2.as_deref(),
-    ) {
-        results.push(Outcome {
-            year,
-            day: day.name.strip_prefix("day").unwrap(),
-            part: if example { "2ex" } else { "2" }.to_string(),
-            result,
-            solution: input.solution2.unwrap_or_default(),
-            correct,
-            elapsed_ms,
-        });
+    let regressions = baseline
+        .map(|baseline| bench::regressions(&benchmarks, &baseline, args.threshold).len())
+        .unwrap_or_default();
+    if regressions > 0 {
+        println!(
+            "{regressions} parts are more than {}% slower than the baseline",
+            args.threshold
+        );
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
     }
 }
 
-fn run_part(
-    input: &str,
-    solver: solvers::SolveFn,
-    solution: Option<&str>,
-) -> Option<(String, bool, i32)> {
-    let now = Instant::now();
-    match solver(input) {
-        Ok(result) => {
-            let correct = solution.is_some_and(|expected| expected == result);
-            Some((
-                result,
-                correct,
-                now.elapsed().as_millis().try_into().unwrap(),
-            ))
-        }
-        Err(e) => {
-            eprintln!("{e}");
-            None
-        }
+fn parse_timeout(arg: &str) -> Result<f64, String> {
+    match arg.parse::<f64>() {
+        Ok(seconds) if seconds > 0.0 && seconds.is_finite() => Ok(seconds),
+        _ => Err("expected a positive number of seconds".to_string()),
+    }
+}
+
+/// The days selected by the arguments, in the order they are registered
+fn select_days(args: &Args) -> Vec<(&'static str, &'static solvers::Day)> {
+    let mut ranges = args.days.clone();
+    if let Some(year) = args.year {
+        ranges.push(DayRange {
+            year,
+            days: args.day.map(|day| day..=day),
+        });
+    }
+    Selection {
+        ranges,
+        tag: args.tag.clone(),
+        last_only: args.last_only,
     }
+    .days()
 }
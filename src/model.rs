@@ -1,19 +1,86 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::history::History;
+
+/// Named parameters of an input, e.g. constants that differ between the examples and the input
+pub type Params = HashMap<String, String>;
+
 /// A problem contains the input data for part1 and optionally part2.
 /// It may contain solutions for these problems.
 pub struct Input {
+    /// Appended to the part in outcomes, e.g. `ex` for the example or `ex2` for the second example
+    pub label: String,
     pub part1: String,
     pub part2: Option<String>,
     pub solution1: Option<String>,
     pub solution2: Option<String>,
+    pub params: Params,
+    /// The answers that were rejected before, only known for real inputs
+    pub history: History,
+}
+
+/// The status of a solved problem compared to its known solution
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    /// The result matches the known solution
+    Correct,
+    /// The result does not match the known solution
+    Incorrect,
+    /// There is no known solution to compare against
+    Unverified,
+    /// The solver returned an error
+    Errored,
+    /// The solver is not implemented yet
+    NotImplemented,
+    /// The input file could not be read
+    MissingInput,
+}
+
+impl Status {
+    pub const ALL: [Status; 6] = [
+        Status::Correct,
+        Status::Incorrect,
+        Status::Errored,
+        Status::MissingInput,
+        Status::Unverified,
+        Status::NotImplemented,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Status::Correct => "correct",
+            Status::Incorrect => "incorrect",
+            Status::Unverified => "unverified",
+            Status::Errored => "errored",
+            Status::NotImplemented => "not implemented",
+            Status::MissingInput => "missing input",
+        }
+    }
+
+    /// Returns true if this status should fail a check. Parts without a verified answer only fail
+    /// in strict mode.
+    pub fn fails_check(self, strict: bool) -> bool {
+        match self {
+            Status::Correct => false,
+            Status::Incorrect | Status::Errored => true,
+            Status::Unverified | Status::NotImplemented | Status::MissingInput => strict,
+        }
+    }
 }
 
 /// The outcome contains results of solving a problem
 pub struct Outcome {
     pub year: &'static str,
     pub day: &'static str,
+    /// The title of the puzzle, if the day declares one
+    pub title: Option<&'static str>,
     pub part: String,
     pub result: String,
     pub solution: String,
-    pub correct: bool,
-    pub elapsed_ms: i32,
+    pub status: Status,
+    /// The time spent parsing the input for this part, if the solver has a parse step
+    pub parse_elapsed: Option<Duration>,
+    pub elapsed: Duration,
+    pub error: Option<String>,
 }
@@ -0,0 +1,326 @@
+use std::fmt::Write;
+use std::time::Duration;
+
+use clap::ValueEnum;
+use comfy_table::{Cell, Color, Table};
+
+use crate::model::{Outcome, Status};
+use crate::solvers::Day;
+
+/// The format used to print the outcomes
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// A human readable table
+    #[default]
+    Table,
+    /// A JSON array with one object per outcome
+    Json,
+    /// Comma separated values with a header row
+    Csv,
+}
+
+pub fn render(format: Format, outcomes: &[Outcome]) -> String {
+    match format {
+        Format::Table => format!("{}\n{}", table(outcomes), counts(outcomes)),
+        Format::Json => json(outcomes),
+        Format::Csv => csv(outcomes),
+    }
+}
+
+fn table(outcomes: &[Outcome]) -> String {
+    let mut table = Table::new();
+    table.set_header(vec![
+        "year", "day", "title", "part", "result", "solution", "status", "parse", "elapsed",
+    ]);
+    for outcome in outcomes {
+        table.add_row(vec![
+            Cell::new(outcome.year),
+            Cell::new(outcome.day),
+            Cell::new(outcome.title.unwrap_or_default()),
+            Cell::new(outcome.part.as_str()),
+            match &outcome.error {
+                Some(error) => Cell::new(error).fg(status_color(outcome.status)),
+                None => Cell::new(outcome.result.as_str()),
+            },
+            Cell::new(outcome.solution.as_str()),
+            Cell::new(outcome.status.as_str()).fg(status_color(outcome.status)),
+            Cell::new(
+                outcome
+                    .parse_elapsed
+                    .map(format_duration)
+                    .unwrap_or_default(),
+            ),
+            Cell::new(format_duration(outcome.elapsed)),
+        ]);
+    }
+    table.to_string()
+}
+
+/// A table of days with their titles, tags and which parts are implemented
+pub fn days(days: &[(&str, &Day)]) -> String {
+    let mut table = Table::new();
+    table.set_header(vec!["year", "day", "title", "tags", "part 1", "part 2"]);
+    for (year, day) in days {
+        let mut row = vec![
+            Cell::new(year),
+            Cell::new(day.number()),
+            Cell::new(day.title.unwrap_or_default()),
+            Cell::new(day.tags.join(", ")),
+        ];
+        for implemented in day.implemented {
+            row.push(if implemented {
+                Cell::new("done").fg(Color::Green)
+            } else {
+                Cell::new("todo").fg(Color::DarkGrey)
+            });
+        }
+        table.add_row(row);
+    }
+    table.to_string()
+}
+
+/// A compact summary listing the parts failing the check and the number of parts per status
+pub fn summary(outcomes: &[Outcome], strict: bool) -> String {
+    let mut out = String::new();
+    for outcome in outcomes.iter().filter(|o| o.status.fails_check(strict)) {
+        write!(
+            out,
+            "FAIL {} day {} part {}: {}",
+            outcome.year,
+            outcome.day,
+            outcome.part,
+            outcome.status.as_str()
+        )
+        .unwrap();
+        match (&outcome.error, outcome.status) {
+            (Some(error), _) => write!(out, " ({error})").unwrap(),
+            (None, Status::Incorrect) => write!(
+                out,
+                " (got {:?}, expected {:?})",
+                outcome.result, outcome.solution
+            )
+            .unwrap(),
+            _ => {}
+        }
+        out.push('\n');
+    }
+    out.push_str(&counts(outcomes));
+    out
+}
+
+/// A single line with the number of parts per status
+fn counts(outcomes: &[Outcome]) -> String {
+    let counts = Status::ALL.map(|status| {
+        let count = outcomes.iter().filter(|o| o.status == status).count();
+        format!("{count} {}", status.as_str())
+    });
+    format!("{} parts: {}", outcomes.len(), counts.join(", "))
+}
+
+fn status_color(status: Status) -> Color {
+    match status {
+        Status::Correct => Color::Green,
+        Status::Incorrect => Color::Red,
+        Status::Unverified => Color::Yellow,
+        Status::Errored => Color::Magenta,
+        Status::NotImplemented => Color::DarkGrey,
+        Status::MissingInput => Color::DarkYellow,
+    }
+}
+
+/// Formats a duration with the largest unit that keeps the value at or above one
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    if nanos < 1_000 {
+        format!("{nanos} ns")
+    } else if nanos < 1_000_000 {
+        format!("{:.1} µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.1} ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2} s", duration.as_secs_f64())
+    }
+}
+
+/// The field names shared by the json and csv formats, in output order
+const FIELDS: [&str; 10] = [
+    "year",
+    "day",
+    "title",
+    "part",
+    "result",
+    "solution",
+    "status",
+    "parse_ns",
+    "elapsed_ns",
+    "error",
+];
+
+fn json(outcomes: &[Outcome]) -> String {
+    let mut out = String::from("[");
+    for (i, outcome) in outcomes.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        let values = [
+            json_string(outcome.year),
+            json_string(outcome.day),
+            outcome.title.map_or("null".to_string(), json_string),
+            json_string(&outcome.part),
+            json_string(&outcome.result),
+            json_string(&outcome.solution),
+            json_string(outcome.status.as_str()),
+            outcome
+                .parse_elapsed
+                .map_or("null".to_string(), |d| d.as_nanos().to_string()),
+            outcome.elapsed.as_nanos().to_string(),
+            outcome
+                .error
+                .as_deref()
+                .map_or("null".to_string(), json_string),
+        ];
+        out.push_str("\n  {");
+        for (j, (field, value)) in FIELDS.iter().zip(values).enumerate() {
+            if j > 0 {
+                out.push_str(", ");
+            }
+            write!(out, "\"{field}\": {value}").unwrap();
+        }
+        out.push('}');
+    }
+    if !outcomes.is_empty() {
+        out.push('\n');
+    }
+    out.push(']');
+    out
+}
+
+fn json_string(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn csv(outcomes: &[Outcome]) -> String {
+    let mut out = FIELDS.join(",");
+    for outcome in outcomes {
+        let values = [
+            csv_field(outcome.year),
+            csv_field(outcome.day),
+            csv_field(outcome.title.unwrap_or_default()),
+            csv_field(&outcome.part),
+            csv_field(&outcome.result),
+            csv_field(&outcome.solution),
+            csv_field(outcome.status.as_str()),
+            outcome
+                .parse_elapsed
+                .map(|d| d.as_nanos().to_string())
+                .unwrap_or_default(),
+            outcome.elapsed.as_nanos().to_string(),
+            csv_field(outcome.error.as_deref().unwrap_or_default()),
+        ];
+        out.push('\n');
+        out.push_str(&values.join(","));
+    }
+    out
+}
+
+/// Quotes a field if it contains a separator, a quote or a line break
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outcome(result: &str, error: Option<&str>) -> Outcome {
+        Outcome {
+            year: "2025",
+            day: "01",
+            title: None,
+            part: "1ex".to_string(),
+            result: result.to_string(),
+            solution: "3".to_string(),
+            status: Status::Incorrect,
+            parse_elapsed: None,
+            elapsed: Duration::from_micros(12),
+            error: error.map(String::from),
+        }
+    }
+
+    #[test]
+    fn json_escapes_strings() {
+        assert_eq!(json_string("a\"b\\c\nd"), "\"a\\\"b\\\\c\\nd\"");
+        assert_eq!(json_string("\u{1}"), "\"\\u0001\"");
+    }
+
+    #[test]
+    fn json_output() {
+        assert_eq!(json(&[]), "[]");
+        let mut titled = outcome("", Some("bad"));
+        titled.title = Some("Secret Entrance");
+        assert_eq!(
+            json(&[outcome("4", None), titled]),
+            "[\n  {\"year\": \"2025\", \"day\": \"01\", \"title\": null, \"part\": \"1ex\", \
+             \"result\": \"4\", \"solution\": \"3\", \"status\": \"incorrect\", \"parse_ns\": null, \
+             \"elapsed_ns\": 12000, \"error\": null},\
+             \n  {\"year\": \"2025\", \"day\": \"01\", \"title\": \"Secret Entrance\", \
+             \"part\": \"1ex\", \"result\": \"\", \"solution\": \"3\", \"status\": \"incorrect\", \
+             \"parse_ns\": null, \"elapsed_ns\": 12000, \"error\": \"bad\"}\n]"
+        );
+    }
+
+    #[test]
+    fn summary_output() {
+        let mut correct = outcome("3", None);
+        correct.status = Status::Correct;
+        let mut unverified = outcome("3", None);
+        unverified.status = Status::Unverified;
+        let outcomes = [correct, outcome("4", None), unverified];
+
+        assert_eq!(
+            summary(&outcomes, false),
+            "FAIL 2025 day 01 part 1ex: incorrect (got \"4\", expected \"3\")\n\
+             3 parts: 1 correct, 1 incorrect, 0 errored, 0 missing input, 1 unverified, \
+             0 not implemented"
+        );
+        assert!(summary(&outcomes, true).contains("FAIL 2025 day 01 part 1ex: unverified\n"));
+    }
+
+    #[test]
+    fn duration_units() {
+        assert_eq!(format_duration(Duration::from_nanos(999)), "999 ns");
+        assert_eq!(format_duration(Duration::from_nanos(1_260)), "1.3 µs");
+        assert_eq!(format_duration(Duration::from_micros(45_678)), "45.7 ms");
+        assert_eq!(format_duration(Duration::from_millis(2_346)), "2.35 s");
+    }
+
+    #[test]
+    fn csv_output() {
+        let mut titled = outcome("a,b", Some("say \"hi\""));
+        titled.title = Some("Lobby, Again");
+        assert_eq!(
+            csv(&[outcome("#.\n.#", None), titled]),
+            "year,day,title,part,result,solution,status,parse_ns,elapsed_ns,error\n\
+             2025,01,,1ex,\"#.\n.#\",3,incorrect,,12000,\n\
+             2025,01,\"Lobby, Again\",1ex,\"a,b\",3,incorrect,,12000,\"say \"\"hi\"\"\""
+        );
+    }
+}
@@ -0,0 +1,392 @@
+use std::any::Any;
+use std::cell::Cell;
+use std::error::Error;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, Once, mpsc};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::history::History;
+use crate::input::{self, SolutionMode};
+use crate::model::{Input, Outcome, Params, Status};
+use crate::output::format_duration;
+use crate::solvers::{self, Answer, Day, ParseFn, Parsed, SolveError, Solver};
+
+/// A day to run with either its examples or its real input
+pub struct Task {
+    pub year: &'static str,
+    pub day: &'static Day,
+    pub example: bool,
+}
+
+pub struct Options {
+    pub mode: SolutionMode,
+    /// The number of tasks to run in parallel
+    pub jobs: usize,
+    /// The time after which a part is abandoned and reported as errored
+    pub timeout: Option<Duration>,
+    /// The only part to run, both parts run if this is `None`
+    pub part: Option<u8>,
+}
+
+impl Options {
+    fn runs_part(&self, part: u8) -> bool {
+        self.part.is_none_or(|p| p == part)
+    }
+}
+
+/// Runs the tasks on up to `jobs` threads. The outcomes are returned in the order of the tasks,
+/// regardless of the order in which they finish.
+pub fn run(tasks: &[Task], options: &Options) -> Vec<Outcome> {
+    if options.jobs <= 1 {
+        return tasks
+            .iter()
+            .flat_map(|task| run_day(task.year, task.day, task.example, options))
+            .collect();
+    }
+
+    let next = AtomicUsize::new(0);
+    let finished: Mutex<Vec<Option<Vec<Outcome>>>> =
+        Mutex::new(tasks.iter().map(|_| None).collect());
+    thread::scope(|scope| {
+        for _ in 0..options.jobs.min(tasks.len()) {
+            scope.spawn(|| {
+                loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(task) = tasks.get(index) else {
+                        break;
+                    };
+                    let outcomes = run_day(task.year, task.day, task.example, options);
+                    finished.lock().unwrap()[index] = Some(outcomes);
+                }
+            });
+        }
+    });
+
+    finished
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .flatten()
+        .flatten()
+        .collect()
+}
+
+/// Solves both parts of a single input that did not come from the inputs directory
+pub fn run_input(
+    year: &'static str,
+    day: &'static Day,
+    input: Input,
+    options: &Options,
+) -> Vec<Outcome> {
+    solve(year, day, input, options)
+}
+
+/// An input, or the label of its parts and why it could not be read
+type Loaded = Result<Input, (String, Box<dyn Error>)>;
+
+fn run_day(
+    year: &'static str,
+    day: &'static Day,
+    example: bool,
+    options: &Options,
+) -> Vec<Outcome> {
+    let mut results = vec![];
+
+    // inputs that cannot be read are reported with the label of their parts
+    let inputs: Vec<Loaded> = if example {
+        match input::get_examples(year, day.name, options.mode) {
+            Ok(examples) => examples
+                .into_iter()
+                .map(|example| example.map_err(|e| (e.label, e.error.into())))
+                .collect(),
+            Err(e) => vec![Err(("ex".to_string(), e))],
+        }
+    } else {
+        vec![input::get_problem(year, day.name, options.mode).map_err(|e| (String::new(), e))]
+    };
+    for input in inputs {
+        match input {
+            Ok(input) => results.extend(solve(year, day, input, options)),
+            Err((label, error)) => {
+                let status = unreadable_status(error.as_ref());
+                for part in [1, 2].into_iter().filter(|part| options.runs_part(*part)) {
+                    results.push(Outcome {
+                        year,
+                        day: day.number(),
+                        title: day.title,
+                        part: format!("{part}{label}"),
+                        result: String::new(),
+                        solution: String::new(),
+                        status,
+                        parse_elapsed: None,
+                        elapsed: Duration::ZERO,
+                        error: Some(error.to_string()),
+                    });
+                }
+            }
+        }
+    }
+    results
+}
+
+/// Inputs whose files do not exist are missing, any other failure to read them, e.g. a malformed
+/// manifest, is an error
+fn unreadable_status(error: &(dyn Error + 'static)) -> Status {
+    match error.downcast_ref::<std::io::Error>() {
+        Some(e) if e.kind() == std::io::ErrorKind::NotFound => Status::MissingInput,
+        _ => Status::Errored,
+    }
+}
+
+/// Solves both parts of a single input
+fn solve(year: &'static str, day: &'static Day, input: Input, options: &Options) -> Vec<Outcome> {
+    let mut results = vec![];
+    let part1 = format!("1{}", input.label);
+    let part2 = format!("2{}", input.label);
+    let text1: Arc<str> = input.part1.into();
+    let text2: Arc<str> = input.part2.map_or_else(|| text1.clone(), Arc::from);
+    let params = Arc::new(input.params);
+    match day.solver {
+        Solver::Direct {
+            part1: solve1,
+            part2: solve2,
+        } => {
+            for (part, label, solution, solve, text) in [
+                (1, part1, input.solution1, solve1, text1),
+                (2, part2, input.solution2, solve2, text2),
+            ] {
+                if !options.runs_part(part) {
+                    continue;
+                }
+                let params = params.clone();
+                let run = isolated(options.timeout, move || {
+                    solvers::with_params(&params, || solve(&text))
+                });
+                let mut outcome = outcome(year, day, label, solution, None, run, options.mode);
+                check_history(&mut outcome, &input.history, part);
+                results.push(outcome);
+            }
+        }
+        Solver::Parsed {
+            parse,
+            part1: solve1,
+            part2: solve2,
+        } => {
+            // the parsed input is only shared if part 2 has no input of its own
+            let shared = Arc::ptr_eq(&text1, &text2);
+            let parsed1 = parse_isolated(parse, text1, &params, options.timeout);
+            let parsed2 = if shared {
+                parsed1.clone()
+            } else {
+                parse_isolated(parse, text2, &params, options.timeout)
+            };
+
+            // the parse time is shown on the first part that runs with the parsed input
+            for (part, label, solution, parsed, solve, parse_elapsed) in [
+                (1, part1, input.solution1, parsed1, solve1, true),
+                (
+                    2,
+                    part2,
+                    input.solution2,
+                    parsed2,
+                    solve2,
+                    !shared || !options.runs_part(1),
+                ),
+            ] {
+                if !options.runs_part(part) {
+                    continue;
+                }
+                let (run, parse_elapsed) = match parsed {
+                    Ok((parsed, elapsed)) => {
+                        let params = params.clone();
+                        let run = isolated(options.timeout, move || {
+                            solvers::with_params(&params, || solve(parsed.as_ref().as_ref()))
+                        });
+                        (run, parse_elapsed.then_some(elapsed))
+                    }
+                    Err(e) => (Err(e), None),
+                };
+                let mut outcome =
+                    outcome(year, day, label, solution, parse_elapsed, run, options.mode);
+                check_history(&mut outcome, &input.history, part);
+                results.push(outcome);
+            }
+        }
+    }
+    results
+}
+
+/// The result of running a solver in isolation and how long it took. The error describes why the
+/// solver did not return, i.e. because it panicked or timed out.
+pub type Isolated<T> = Result<(T, Duration), String>;
+
+thread_local! {
+    /// Whether a solver runs in isolation on this thread, its panics are reported in the results
+    static ISOLATED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Installs a panic hook that keeps quiet about the panics caught by [`isolated`] and otherwise
+/// behaves like the default hook
+fn install_panic_hook() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let default = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !ISOLATED.get() {
+                default(info);
+            }
+        }));
+    });
+}
+
+/// Runs `f` and measures how long it takes. Panics are caught and reported as errors. If there is
+/// a timeout, `f` runs on its own thread, which is abandoned once the timeout expires.
+pub fn isolated<T: Send + 'static>(
+    timeout: Option<Duration>,
+    f: impl FnOnce() -> T + Send + 'static,
+) -> Isolated<T> {
+    install_panic_hook();
+    let run = move || {
+        let now = Instant::now();
+        ISOLATED.set(true);
+        let result = panic::catch_unwind(AssertUnwindSafe(f));
+        ISOLATED.set(false);
+        result.map(|value| (value, now.elapsed()))
+    };
+    let result = match timeout {
+        None => run(),
+        Some(timeout) => {
+            let (sender, receiver) = mpsc::channel();
+            thread::spawn(move || sender.send(run()));
+            receiver
+                .recv_timeout(timeout)
+                .map_err(|_| format!("timed out after {}", format_duration(timeout)))?
+        }
+    };
+    result.map_err(|payload| format!("panicked: {}", panic_message(payload.as_ref())))
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "unknown panic payload"
+    }
+}
+
+/// Parses the input in isolation, parse errors are reported like panics so both parts fail
+fn parse_isolated(
+    parse: ParseFn,
+    text: Arc<str>,
+    params: &Arc<Params>,
+    timeout: Option<Duration>,
+) -> Isolated<Arc<Parsed>> {
+    let params = params.clone();
+    let (parsed, elapsed) = isolated(timeout, move || {
+        solvers::with_params(&params, || parse(&text))
+    })?;
+    match parsed {
+        Ok(parsed) => Ok((Arc::new(parsed), elapsed)),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+fn outcome(
+    year: &'static str,
+    day: &'static Day,
+    part: String,
+    solution: Option<String>,
+    parse_elapsed: Option<Duration>,
+    run: Isolated<Result<Answer, SolveError>>,
+    mode: SolutionMode,
+) -> Outcome {
+    let (result, elapsed) = match run {
+        Ok((result, elapsed)) => (Ok(result), elapsed),
+        Err(e) => (Err(e), Duration::ZERO),
+    };
+    let (result, status, error) = match result {
+        Ok(Ok(answer)) => {
+            let matches = |expected: &str| match mode {
+                SolutionMode::Normalized => answer.matches(expected),
+                SolutionMode::Exact => answer.matches_exactly(expected),
+            };
+            let status = match &solution {
+                Some(expected) if matches(expected) => Status::Correct,
+                Some(_) => Status::Incorrect,
+                None => Status::Unverified,
+            };
+            (answer.to_string(), status, None)
+        }
+        Ok(Err(SolveError::Unimplemented)) => (String::new(), Status::NotImplemented, None),
+        Ok(Err(e)) => (String::new(), Status::Errored, Some(e.to_string())),
+        Err(e) => (String::new(), Status::Errored, Some(e)),
+    };
+
+    Outcome {
+        year,
+        day: day.number(),
+        title: day.title,
+        part,
+        result,
+        solution: solution.unwrap_or_default(),
+        status,
+        parse_elapsed,
+        elapsed,
+        error,
+    }
+}
+
+/// Marks answers without a known solution as incorrect if the history rules them out
+fn check_history(outcome: &mut Outcome, history: &History, part: u8) {
+    if outcome.status != Status::Unverified {
+        return;
+    }
+    if let Err(e) = history.check(part, &outcome.result) {
+        outcome.status = Status::Incorrect;
+        outcome.error = Some(e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unreadable_inputs() {
+        let missing = std::io::Error::new(std::io::ErrorKind::NotFound, "part1.txt");
+        assert_eq!(unreadable_status(&missing), Status::MissingInput);
+        let encrypted = std::io::Error::other("part1.txt.enc: encrypted with another key");
+        assert_eq!(unreadable_status(&encrypted), Status::Errored);
+        let manifest: Box<dyn Error> = "manifest.toml: line 1: expected a key".into();
+        assert_eq!(unreadable_status(manifest.as_ref()), Status::Errored);
+    }
+
+    #[test]
+    fn isolated_returns_value() {
+        let (value, _) = isolated(None, || 42).unwrap();
+        assert_eq!(value, 42);
+    }
+
+    #[test]
+    fn isolated_catches_panics() {
+        let result = isolated(None, || -> i32 { panic!("boom") });
+        assert_eq!(result.unwrap_err(), "panicked: boom");
+
+        let result = isolated(Some(Duration::from_secs(10)), || -> i32 {
+            panic!("{}", "formatted")
+        });
+        assert_eq!(result.unwrap_err(), "panicked: formatted");
+    }
+
+    #[test]
+    fn isolated_times_out() {
+        let result = isolated(Some(Duration::from_millis(10)), || {
+            thread::sleep(Duration::from_secs(1));
+        });
+        assert_eq!(result.unwrap_err(), "timed out after 10.0 ms");
+    }
+}
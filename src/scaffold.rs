@@ -0,0 +1,57 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::input;
+
+const DAY_TEMPLATE: &str = r#"crate::part1_todo!();
+crate::part2_todo!();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solvers::SolveError;
+
+    const EXAMPLE: &str = "";
+
+    #[test]
+    fn test_part1() {
+        assert!(matches!(part1(EXAMPLE), Err(SolveError::Unimplemented)));
+    }
+}
+"#;
+
+fn solvers_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src")
+        .join("solvers")
+}
+
+/// Creates the solver file of a new day from a template and its input directory. The day is
+/// registered by the build script the next time the solvers are built.
+pub fn new_day(year: u32, day: u32) -> Result<(), String> {
+    let year = year.to_string();
+    let day = format!("day{day:02}");
+    let year_dir = solvers_dir().join(format!("year{year}"));
+    let day_file = year_dir.join(format!("{day}.rs"));
+
+    if day_file.exists() {
+        return Err(format!("{} already exists", day_file.display()));
+    }
+    fs::create_dir_all(&year_dir).map_err(|e| format!("{}: {e}", year_dir.display()))?;
+    write(&day_file, DAY_TEMPLATE)?;
+
+    let input_dir = input::day_dir(&year, &day);
+    fs::create_dir_all(&input_dir).map_err(|e| format!("{}: {e}", input_dir.display()))?;
+    let example = input_dir.join("part1_ex.txt");
+    if !example.exists() {
+        write(&example, "")?;
+    }
+    println!("Paste the example into {}", example.display());
+    Ok(())
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    fs::write(path, contents).map_err(|e| format!("{}: {e}", path.display()))?;
+    println!("Wrote {}", path.display());
+    Ok(())
+}
@@ -0,0 +1,146 @@
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use crate::solvers::{self, Day};
+
+/// A year and optionally a range of its days, written as `2025`, `2025:7` or `2025:1-5`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DayRange {
+    pub year: u32,
+    pub days: Option<RangeInclusive<u32>>,
+}
+
+impl DayRange {
+    fn contains(&self, year: u32, day: u32) -> bool {
+        self.year == year && self.days.as_ref().is_none_or(|days| days.contains(&day))
+    }
+}
+
+impl FromStr for DayRange {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let number = |n: &str| {
+            n.trim()
+                .parse::<u32>()
+                .map_err(|_| format!("invalid number '{n}' in '{s}'"))
+        };
+        let (year, days) = match s.split_once(':') {
+            None => (number(s)?, None),
+            Some((year, days)) => {
+                let days = match days.split_once('-') {
+                    None => number(days)?..=number(days)?,
+                    Some((first, last)) => number(first)?..=number(last)?,
+                };
+                if days.is_empty() {
+                    return Err(format!("empty range of days in '{s}'"));
+                }
+                (number(year)?, Some(days))
+            }
+        };
+        Ok(DayRange { year, days })
+    }
+}
+
+/// Which days to run. Days are selected if they are in any of the ranges, or in all years if
+/// there are no ranges, and have the tag if there is one.
+#[derive(Debug, Default)]
+pub struct Selection {
+    pub ranges: Vec<DayRange>,
+    pub tag: Option<String>,
+    /// Select only the last day of the latest year, ignoring everything else
+    pub last_only: bool,
+}
+
+impl Selection {
+    fn matches(&self, year: u32, day_number: u32, tags: &[&str]) -> bool {
+        let in_range = self.ranges.is_empty()
+            || self
+                .ranges
+                .iter()
+                .any(|range| range.contains(year, day_number));
+        let tagged = self
+            .tag
+            .as_ref()
+            .is_none_or(|tag| tags.contains(&tag.as_str()));
+        in_range && tagged
+    }
+
+    /// Returns the selected days in the order they are registered
+    pub fn days(&self) -> Vec<(&'static str, &'static Day)> {
+        if self.last_only {
+            return solvers::YEARS
+                .last()
+                .and_then(|(year, days)| Some((*year, days.last()?)))
+                .into_iter()
+                .collect();
+        }
+
+        let mut selected = vec![];
+        for (year, days) in solvers::YEARS {
+            for day in *days {
+                // unwrapping here is safe because years and day numbers are numbers
+                let year_number = year.parse().unwrap();
+                let day_number = day.number().parse().unwrap();
+                if self.matches(year_number, day_number, day.tags) {
+                    selected.push((*year, day));
+                }
+            }
+        }
+        selected
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_ranges() {
+        assert_eq!(
+            "2025".parse(),
+            Ok(DayRange {
+                year: 2025,
+                days: None
+            })
+        );
+        assert_eq!(
+            "2025:7".parse(),
+            Ok(DayRange {
+                year: 2025,
+                days: Some(7..=7)
+            })
+        );
+        assert_eq!(
+            "2025:1-5".parse(),
+            Ok(DayRange {
+                year: 2025,
+                days: Some(1..=5)
+            })
+        );
+        assert!("2025:5-1".parse::<DayRange>().is_err());
+        assert!("2025:".parse::<DayRange>().is_err());
+        assert!("twenty".parse::<DayRange>().is_err());
+    }
+
+    #[test]
+    fn matches_ranges_and_tags() {
+        let selection = Selection {
+            ranges: vec!["2025:1-5".parse().unwrap(), "2024:10".parse().unwrap()],
+            tag: None,
+            last_only: false,
+        };
+        assert!(selection.matches(2025, 1, &[]));
+        assert!(selection.matches(2025, 5, &[]));
+        assert!(!selection.matches(2025, 6, &[]));
+        assert!(selection.matches(2024, 10, &[]));
+        assert!(!selection.matches(2024, 1, &[]));
+
+        let selection = Selection {
+            tag: Some("grid".to_string()),
+            ..Selection::default()
+        };
+        assert!(selection.matches(2019, 1, &["grid", "graph"]));
+        assert!(!selection.matches(2019, 1, &["graph"]));
+    }
+}
@@ -1,43 +1,112 @@
-mod year2019;
-mod year2024;
-mod year2025;
+use std::any::Any;
+use std::cell::RefCell;
+use std::str::FromStr;
 
-pub type SolveFn = fn(&str) -> Result<String, String>;
+use crate::model::Params;
+
+mod answer;
+
+pub use answer::{Answer, SolveError};
+
+pub type SolveFn = fn(&str) -> Result<Answer, SolveError>;
+
+/// The type erased output of a parse step, it is shared between threads
+pub type Parsed = Box<dyn Any + Send + Sync>;
+pub type ParseFn = fn(&str) -> Result<Parsed, SolveError>;
+pub type PartFn = fn(&dyn Any) -> Result<Answer, SolveError>;
+
+pub enum Solver {
+    /// Both parts solve the raw input
+    Direct { part1: SolveFn, part2: SolveFn },
+    /// The input is parsed once and the parsed input is shared by both parts
+    Parsed {
+        parse: ParseFn,
+        part1: PartFn,
+        part2: PartFn,
+    },
+}
 
 pub struct Day {
     pub name: &'static str,
-    pub part1: SolveFn,
-    pub part2: SolveFn,
+    /// The puzzle title, declared by the day as `pub const TITLE: &str`
+    pub title: Option<&'static str>,
+    /// Topics of the puzzle like `grid` or `graph`, declared by the day as `pub const TAGS`
+    pub tags: &'static [&'static str],
+    /// Whether part 1 and part 2 are implemented, i.e. not stubbed with `part1_todo!`/`part2_todo!`
+    pub implemented: [bool; 2],
+    pub solver: Solver,
 }
 
-pub static YEARS: &[(&str, &[Day])] = &[
-    ("2019", year2019::DAYS),
-    ("2024", year2024::DAYS),
-    ("2025", year2025::DAYS),
-];
+impl Day {
+    /// The number of the day as written in its name, e.g. `07` for `day07`
+    pub fn number(&self) -> &'static str {
+        // unwrapping here is safe because day names always start with "day"
+        self.name.strip_prefix("day").unwrap()
+    }
+}
 
+/// Recovers the output of `parse` from the parsed input. Only used by [`day_solver!`], which
+/// guarantees that the parsed input was created by the same `parse` function.
+pub fn downcast<T: Any>(_parse: fn(&str) -> Result<T, SolveError>, parsed: &dyn Any) -> &T {
+    parsed
+        .downcast_ref()
+        .expect("parsed input was created by a different parse function")
+}
+
+thread_local! {
+    static PARAMS: RefCell<Params> = RefCell::new(Params::new());
+}
+
+/// Runs `f` with the parameters of an input, so solvers called by `f` can read them with [`param`]
+pub fn with_params<T>(params: &Params, f: impl FnOnce() -> T) -> T {
+    let previous = PARAMS.replace(params.clone());
+    let result = f();
+    PARAMS.set(previous);
+    result
+}
+
+/// Returns the parameter of the current input with the given name, if it is set and parses
+pub fn param<T: FromStr>(name: &str) -> Option<T> {
+    PARAMS.with_borrow(|params| params.get(name)?.parse().ok())
+}
+
+// the modules of all years and their days, generated by build.rs
+include!(concat!(env!("OUT_DIR"), "/registry.rs"));
+
+/// Creates the solver of a day module. Days marked with `parsed` provide a `parse` function whose
+/// output is passed by reference to `part1` and `part2`, all other days solve the raw input.
 #[macro_export]
-macro_rules! register_days {
-    ($($day:ident),* $(,)?) => {
-        $(mod $day;)*
-
-        pub static DAYS: &[$crate::solvers::Day] = &[
-            $(
-                $crate::solvers::Day {
-                    name: stringify!($day),
-                    part1: $day::part1,
-                    part2: $day::part2,
-                },
-            )*
-        ];
+macro_rules! day_solver {
+    ($day:ident) => {
+        $crate::solvers::Solver::Direct {
+            part1: $day::part1,
+            part2: $day::part2,
+        }
+    };
+    ($day:ident parsed) => {
+        $crate::solvers::Solver::Parsed {
+            parse: |input| {
+                $day::parse(input).map(|parsed| Box::new(parsed) as $crate::solvers::Parsed)
+            },
+            part1: |parsed| {
+                let parsed = $crate::solvers::downcast($day::parse, parsed);
+                $day::part1(parsed)
+            },
+            part2: |parsed| {
+                let parsed = $crate::solvers::downcast($day::parse, parsed);
+                $day::part2(parsed)
+            },
+        }
     };
 }
 
 #[macro_export]
 macro_rules! part1_todo {
     () => {
-        pub fn part1(_input: &str) -> Result<String, String> {
-            Ok("Not implemented".to_string())
+        pub fn part1<T: ?Sized>(
+            _input: &T,
+        ) -> Result<$crate::solvers::Answer, $crate::solvers::SolveError> {
+            Err($crate::solvers::SolveError::Unimplemented)
         }
     };
 }
@@ -45,8 +114,10 @@ macro_rules! part1_todo {
 #[macro_export]
 macro_rules! part2_todo {
     () => {
-        pub fn part2(_input: &str) -> Result<String, String> {
-            Ok("Not implemented".to_string())
+        pub fn part2<T: ?Sized>(
+            _input: &T,
+        ) -> Result<$crate::solvers::Answer, $crate::solvers::SolveError> {
+            Err($crate::solvers::SolveError::Unimplemented)
         }
     };
 }
@@ -0,0 +1,145 @@
+use std::fmt;
+
+/// The answer to one part of a puzzle
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+    /// A multi-line answer drawn with characters, e.g. letters on a screen
+    Art(String),
+}
+
+impl Answer {
+    /// Returns true if the answer matches the expected solution. Numbers are compared by value,
+    /// so surrounding whitespace in the solution does not matter. Art ignores trailing whitespace
+    /// on every line.
+    pub fn matches(&self, expected: &str) -> bool {
+        match self {
+            Answer::Number(value) => expected.trim().parse::<i128>() == Ok(*value),
+            Answer::Text(text) => text == expected,
+            Answer::Art(art) => art
+                .trim_end()
+                .lines()
+                .map(str::trim_end)
+                .eq(expected.trim_end().lines().map(str::trim_end)),
+        }
+    }
+
+    /// Returns true if the answer is written exactly like the expected solution, including any
+    /// whitespace and line endings
+    pub fn matches_exactly(&self, expected: &str) -> bool {
+        self.to_string() == expected
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(value) => write!(f, "{value}"),
+            Answer::Text(text) | Answer::Art(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+macro_rules! impl_from_number {
+    ($($number:ty),*) => {
+        $(
+            impl From<$number> for Answer {
+                fn from(value: $number) -> Self {
+                    Answer::Number(i128::from(value))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_number!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        // usize is at most 64 bits wide on all supported platforms
+        Answer::Number(value as i128)
+    }
+}
+
+impl From<isize> for Answer {
+    fn from(value: isize) -> Self {
+        Answer::Number(value as i128)
+    }
+}
+
+/// Strings spanning multiple lines are treated as art
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        if value.trim_end().contains('\n') {
+            Answer::Art(value)
+        } else {
+            Answer::Text(value)
+        }
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::from(value.to_string())
+    }
+}
+
+/// The reasons a solver can fail
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SolveError {
+    /// The input could not be parsed
+    Parse(String),
+    /// The input was parsed but does not satisfy the assumptions of the solver
+    InvalidInput(String),
+    /// The part is not implemented yet
+    Unimplemented,
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::Parse(e) => write!(f, "parse error: {e}"),
+            SolveError::InvalidInput(e) => write!(f, "invalid input: {e}"),
+            SolveError::Unimplemented => write!(f, "not implemented"),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+impl From<nom::Err<nom::error::Error<&str>>> for SolveError {
+    fn from(e: nom::Err<nom::error::Error<&str>>) -> Self {
+        SolveError::Parse(e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers_match_numerically() {
+        assert!(Answer::from(42_u64).matches("42"));
+        assert!(Answer::from(42_i32).matches(" 42\n"));
+        assert!(Answer::from(-7_i64).matches("-7\r\n"));
+        assert!(!Answer::from(42_usize).matches("042x"));
+        assert!(!Answer::from(42_usize).matches("43"));
+        assert!(Answer::from(42_u64).matches_exactly("42"));
+        assert!(!Answer::from(42_u64).matches_exactly("42\n"));
+    }
+
+    #[test]
+    fn art_ignores_trailing_whitespace() {
+        let art = Answer::from("#..#\n#  #\n");
+        assert!(matches!(art, Answer::Art(_)));
+        assert!(art.matches("#..#  \n#  #\n\n"));
+        assert!(!art.matches("#..#\n####"));
+    }
+
+    #[test]
+    fn text_matches_exactly() {
+        assert!(Answer::from("abc").matches("abc"));
+        assert!(!Answer::from("abc").matches("abd"));
+    }
+}
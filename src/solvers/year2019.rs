@@ -1,3 +0,0 @@
-crate::register_days! {
-    day01,
-}
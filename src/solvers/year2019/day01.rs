@@ -5,22 +5,27 @@ use nom::{
     multi::separated_list1,
 };
 
+use crate::solvers::{Answer, SolveError};
+
+pub const TITLE: &str = "The Tyranny of the Rocket Equation";
+pub const TAGS: &[&str] = &["math"];
+
 fn parse(input: &str) -> IResult<&str, Vec<i32>> {
     // remove trailing whitespace
     let input = input.trim();
     all_consuming(separated_list1(line_ending, nom_i32)).parse(input)
 }
 
-pub fn part1(input: &str) -> Result<String, String> {
-    let (_, masses) = parse(input).map_err(|e| e.to_string())?;
+pub fn part1(input: &str) -> Result<Answer, SolveError> {
+    let (_, masses) = parse(input)?;
 
     let total_fuel: i32 = masses.iter().map(|v| v / 3 - 2).sum();
 
-    Ok(total_fuel.to_string())
+    Ok(total_fuel.into())
 }
 
-pub fn part2(input: &str) -> Result<String, String> {
-    let (_, masses) = parse(input).map_err(|e| e.to_string())?;
+pub fn part2(input: &str) -> Result<Answer, SolveError> {
+    let (_, masses) = parse(input)?;
 
     let total_fuel: i32 = masses
         .iter()
@@ -34,5 +39,5 @@ pub fn part2(input: &str) -> Result<String, String> {
         })
         .sum();
 
-    Ok(total_fuel.to_string())
+    Ok(total_fuel.into())
 }
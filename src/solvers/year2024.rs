@@ -1,5 +0,0 @@
-crate::register_days! {
-    day01,
-    day10,
-    day19,
-}
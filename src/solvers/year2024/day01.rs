@@ -8,6 +8,11 @@ use nom::{
 
 use std::iter::zip;
 
+use crate::solvers::{Answer, SolveError};
+
+pub const TITLE: &str = "Historian Hysteria";
+pub const TAGS: &[&str] = &["sorting"];
+
 fn parse_pair(input: &str) -> IResult<&str, (i32, i32)> {
     separated_pair(nom_i32, multispace1, nom_i32).parse(input)
 }
@@ -19,19 +24,19 @@ fn parse(input: &str) -> IResult<&str, (Vec<i32>, Vec<i32>)> {
     Ok((input, pairs.into_iter().unzip()))
 }
 
-pub fn part1(input: &str) -> Result<String, String> {
-    let (_, (mut left, mut right)) = parse(input).map_err(|e| e.to_string())?;
+pub fn part1(input: &str) -> Result<Answer, SolveError> {
+    let (_, (mut left, mut right)) = parse(input)?;
 
     left.sort_unstable();
     right.sort_unstable();
 
     let distance: i32 = zip(left, right).map(|p| (p.0 - p.1).abs()).sum();
 
-    Ok(distance.to_string())
+    Ok(distance.into())
 }
 
-pub fn part2(input: &str) -> Result<String, String> {
-    let (_, (left, right)) = parse(input).map_err(|e| e.to_string())?;
+pub fn part2(input: &str) -> Result<Answer, SolveError> {
+    let (_, (left, right)) = parse(input)?;
 
     // could use a hashmap here for efficiency
     let similarity: i32 = left
@@ -39,5 +44,5 @@ pub fn part2(input: &str) -> Result<String, String> {
         .map(|&v| v * right.iter().filter(|&rv| *rv == v).count() as i32)
         .sum();
 
-    Ok(similarity.to_string())
+    Ok(similarity.into())
 }
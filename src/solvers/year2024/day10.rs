@@ -7,8 +7,12 @@ use nom::{
     multi::{many1, separated_list1},
 };
 
+use crate::solvers::{Answer, SolveError};
 use crate::utils::{Matrix, Pose, nom_ext};
 
+pub const TITLE: &str = "Hoof It";
+pub const TAGS: &[&str] = &["grid", "search"];
+
 pub fn parse(input: &str) -> IResult<&str, Matrix<i64>> {
     all_consuming(map_res(
         separated_list1(line_ending, many1(nom_ext::one_digit)),
@@ -46,8 +50,8 @@ fn trailhead_score(map: &Matrix<i64>, pos: Pose) -> i64 {
     reachable.len() as i64
 }
 
-pub fn part1(input: &str) -> Result<String, String> {
-    let map = parse(input).map_err(|e| e.to_string())?.1;
+pub fn part1(input: &str) -> Result<Answer, SolveError> {
+    let map = parse(input)?.1;
 
     let mut scores = Vec::new();
     for y in 0..map.height() {
@@ -58,7 +62,7 @@ pub fn part1(input: &str) -> Result<String, String> {
             }
         }
     }
-    Ok(scores.iter().sum::<i64>().to_string())
+    Ok(scores.iter().sum::<i64>().into())
 }
 
 fn trailhead_score_distinct(map: &Matrix<i64>, pos: Pose) -> i64 {
@@ -86,8 +90,8 @@ fn trailhead_score_distinct(map: &Matrix<i64>, pos: Pose) -> i64 {
     reachable.len() as i64
 }
 
-pub fn part2(input: &str) -> Result<String, String> {
-    let map = parse(input).map_err(|e| e.to_string())?.1;
+pub fn part2(input: &str) -> Result<Answer, SolveError> {
+    let map = parse(input)?.1;
     let mut scores = Vec::new();
     for y in 0..map.height() {
         for x in 0..map.width() {
@@ -97,5 +101,5 @@ pub fn part2(input: &str) -> Result<String, String> {
             }
         }
     }
-    Ok(scores.iter().sum::<i64>().to_string())
+    Ok(scores.iter().sum::<i64>().into())
 }
@@ -8,6 +8,11 @@ use nom::{
 
 use std::collections::HashMap;
 
+use crate::solvers::{Answer, SolveError};
+
+pub const TITLE: &str = "Linen Layout";
+pub const TAGS: &[&str] = &["dp", "strings"];
+
 type Pattern<'a> = &'a str;
 
 fn parse_color(input: &str) -> IResult<&str, char> {
@@ -18,15 +23,13 @@ fn parse_pattern(input: &str) -> IResult<&str, &str> {
     alpha1(input.trim())
 }
 
-fn parse(input: &str) -> Result<(Vec<Pattern>, Vec<Pattern>), String> {
+fn parse(input: &str) -> Result<(Vec<Pattern>, Vec<Pattern>), SolveError> {
     let (input, patterns) = separated_list1(tag(","), parse_pattern)
-        .parse(input.trim())
-        .map_err(|e| e.to_string())?
+        .parse(input.trim())?
         .clone();
 
     let (_, designs) = all_consuming(separated_list1(line_ending, parse_pattern))
-        .parse(input.trim())
-        .map_err(|e| e.to_string())?;
+        .parse(input.trim())?;
 
     Ok((patterns, designs))
 }
@@ -47,10 +50,10 @@ fn possible(rest_design: PatternSlice, patterns: &[Pattern]) -> bool {
     false
 }
 
-pub fn part1(input: &str) -> Result<String, String> {
+pub fn part1(input: &str) -> Result<Answer, SolveError> {
     let (patterns, designs) = parse(input)?;
     let possible_designs = designs.iter().filter(|d| possible(d, &patterns)).count();
-    Ok(possible_designs.to_string())
+    Ok(possible_designs.into())
 }
 
 fn evaluate_design(memo: &mut HashMap<String, i32>, design: Pattern) -> i32 {
@@ -66,11 +69,7 @@ fn evaluate_design(memo: &mut HashMap<String, i32>, design: Pattern) -> i32 {
     let keys: Vec<String> = memo.keys().cloned().collect();
     for k in keys {
         if design.starts_with(&k) {
-            let num = evaluate_design(memo, &design[k.len()..]);
-            if num > 0 {
-                println!("{design} can be made in {num}!");
-            }
-            num_variants += num;
+            num_variants += evaluate_design(memo, &design[k.len()..]);
         } else {
             // println!("{design} -> {k}");
         }
@@ -89,18 +88,16 @@ fn evaluate_design(memo: &mut HashMap<String, i32>, design: Pattern) -> i32 {
     // }
 }
 
-pub fn part2(input: &str) -> Result<String, String> {
+pub fn part2(input: &str) -> Result<Answer, SolveError> {
     let (patterns, designs) = parse(input)?;
     let mut memo = HashMap::new();
     for pattern in &patterns {
         memo.insert((*pattern).to_string(), 1);
     }
-    println!("{memo:#?}");
     let mut num_designs = 0;
     for design in &designs {
         if *design == "gbbr" {
-            let num = evaluate_design(&mut memo, &design);
-            println!("{design} -> {num}");
+            evaluate_design(&mut memo, design);
         }
     }
 
@@ -113,5 +110,5 @@ pub fn part2(input: &str) -> Result<String, String> {
     //     })
     //     .sum();
     // Ok(total_count.to_string())
-    Ok(num_designs.to_string())
+    Ok(num_designs.into())
 }
@@ -1,13 +0,0 @@
-crate::register_days! {
-    day01,
-    day02,
-    day03,
-    day04,
-    day05,
-    day06,
-    day07,
-    day08,
-    day10,
-    day11,
-    day12,
-}
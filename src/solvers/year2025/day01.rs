@@ -5,30 +5,30 @@ use nom::{
     multi::separated_list1,
 };
 
+use crate::solvers::{Answer, SolveError};
+
 fn parse_rotation(input: &str) -> IResult<&str, i32> {
     let (input, (dir, count)) = (one_of("LR"), nom_i32).parse(input)?;
     let sign = if dir == 'L' { -1 } else { 1 };
     Ok((input, sign * count))
 }
 
-fn parse(input: &str) -> Result<Vec<i32>, String> {
+pub fn parse(input: &str) -> Result<Vec<i32>, SolveError> {
     let (_, rotations) = all_consuming(separated_list1(line_ending, parse_rotation))
-        .parse(input.trim())
-        .map_err(|e| e.to_string())?;
+        .parse(input.trim())?;
     Ok(rotations)
 }
 
-pub fn part1(input: &str) -> Result<String, String> {
-    let rotations = parse(input)?;
+pub fn part1(rotations: &[i32]) -> Result<Answer, SolveError> {
     let mut zero_count = 0;
     let mut current = 50;
     for increment in rotations {
-        current += increment;
+        current += *increment;
         if current % 100 == 0 {
             zero_count += 1;
         }
     }
-    Ok(zero_count.to_string())
+    Ok(zero_count.into())
 }
 
 fn div_floor(a: i32, b: i32) -> i32 {
@@ -39,11 +39,10 @@ fn to_turn(value: i32) -> i32 {
     div_floor(value, 100)
 }
 
-pub fn part2(input: &str) -> Result<String, String> {
-    let rotations = parse(input)?;
+pub fn part2(rotations: &[i32]) -> Result<Answer, SolveError> {
     let mut zero_count = 0;
     let mut current: i32 = 50;
-    for increment in rotations {
+    for &increment in rotations {
         let previous = current;
         current += increment;
         zero_count += (to_turn(current) - to_turn(previous)).abs();
@@ -57,5 +56,5 @@ pub fn part2(input: &str) -> Result<String, String> {
             }
         }
     }
-    Ok(zero_count.to_string())
+    Ok(zero_count.into())
 }
@@ -3,14 +3,15 @@ use nom::{
     combinator::all_consuming, multi::separated_list1, sequence::separated_pair,
 };
 
+use crate::solvers::{Answer, SolveError};
+
 fn parse_interval(input: &str) -> IResult<&str, (i64, i64)> {
     separated_pair(nom_i64, tag("-"), nom_i64).parse(input)
 }
 
-fn parse(input: &str) -> Result<Vec<(i64, i64)>, String> {
+fn parse(input: &str) -> Result<Vec<(i64, i64)>, SolveError> {
     let (_, intervals) = all_consuming(separated_list1(tag(","), parse_interval))
-        .parse(input.trim())
-        .map_err(|e| e.to_string())?;
+        .parse(input.trim())?;
     Ok(intervals)
 }
 
@@ -53,7 +54,7 @@ fn invalid(i: i64) -> bool {
     }
 }
 
-pub fn part1(input: &str) -> Result<String, String> {
+pub fn part1(input: &str) -> Result<Answer, SolveError> {
     let intervals = parse(input)?;
     let mut sum = 0;
     for interval in &intervals {
@@ -63,7 +64,7 @@ pub fn part1(input: &str) -> Result<String, String> {
             }
         }
     }
-    Ok(sum.to_string())
+    Ok(sum.into())
 }
 
 fn is_prime(candidate: usize) -> bool {
@@ -94,7 +95,7 @@ fn grouped_invalid(num: i64, groupings: &[usize]) -> bool {
     false
 }
 
-pub fn part2(input: &str) -> Result<String, String> {
+pub fn part2(input: &str) -> Result<Answer, SolveError> {
     let intervals = parse(input)?;
 
     // We only need to check prime groupings up to the number of digits in an i64
@@ -111,7 +112,7 @@ pub fn part2(input: &str) -> Result<String, String> {
         })
         .sum();
 
-    Ok(sum.to_string())
+    Ok(sum.into())
 }
 
 #[cfg(test)]
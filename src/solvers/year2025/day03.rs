@@ -5,16 +5,16 @@ use nom::{
     multi::{many1, separated_list1},
 };
 
+use crate::solvers::{Answer, SolveError};
 use crate::utils::nom_ext::one_digit;
 
 fn parse_bank(input: &str) -> IResult<&str, Vec<i64>> {
     many1(one_digit).parse(input)
 }
 
-fn parse(input: &str) -> Result<Vec<Vec<i64>>, String> {
+fn parse(input: &str) -> Result<Vec<Vec<i64>>, SolveError> {
     let (_, banks) = all_consuming(separated_list1(line_ending, parse_bank))
-        .parse(input.trim())
-        .map_err(|e| e.to_string())?;
+        .parse(input.trim())?;
     Ok(banks)
 }
 
@@ -33,14 +33,14 @@ fn joltage<const N: usize>(bank: Vec<i64>) -> i64 {
     candidates.iter().fold(0, |acc, x| acc * 10 + x)
 }
 
-pub fn part1(input: &str) -> Result<String, String> {
+pub fn part1(input: &str) -> Result<Answer, SolveError> {
     let banks = parse(input)?;
     let total_joltage: i64 = banks.into_iter().map(joltage::<2>).sum();
-    Ok(total_joltage.to_string())
+    Ok(total_joltage.into())
 }
 
-pub fn part2(input: &str) -> Result<String, String> {
+pub fn part2(input: &str) -> Result<Answer, SolveError> {
     let banks = parse(input)?;
     let total_joltage: i64 = banks.into_iter().map(joltage::<12>).sum();
-    Ok(total_joltage.to_string())
+    Ok(total_joltage.into())
 }
@@ -5,13 +5,16 @@ use nom::{
     multi::{many1, separated_list1},
 };
 
+use crate::solvers::{Answer, SolveError};
 use crate::utils::{Matrix, Vector};
 
+pub const TAGS: &[&str] = &["grid"];
+
 pub fn parse_is_roll(input: &str) -> IResult<&str, bool> {
     map(one_of("@."), |c| c == '@').parse(input)
 }
 
-pub fn parse(input: &str) -> Result<Matrix<bool>, String> {
+pub fn parse(input: &str) -> Result<Matrix<bool>, SolveError> {
     let (_, map) = all_consuming(map_res(
         separated_list1(line_ending, many1(parse_is_roll)),
         |lines| {
@@ -20,8 +23,7 @@ pub fn parse(input: &str) -> Result<Matrix<bool>, String> {
             Matrix::from_vec(width, values)
         },
     ))
-    .parse(input.trim())
-    .map_err(|e| e.to_string())?;
+    .parse(input.trim())?;
     Ok(map)
 }
 
@@ -39,15 +41,15 @@ fn get_removable(map: &Matrix<bool>) -> Vec<Vector> {
         .collect()
 }
 
-pub fn part1(input: &str) -> Result<String, String> {
+pub fn part1(input: &str) -> Result<Answer, SolveError> {
     let map = parse(input)?;
 
     let total = get_removable(&map).len();
 
-    Ok(total.to_string())
+    Ok(total.into())
 }
 
-pub fn part2(input: &str) -> Result<String, String> {
+pub fn part2(input: &str) -> Result<Answer, SolveError> {
     let mut map = parse(input)?;
 
     let mut total = 0;
@@ -64,5 +66,5 @@ pub fn part2(input: &str) -> Result<String, String> {
         }
     }
 
-    Ok(total.to_string())
+    Ok(total.into())
 }
@@ -6,8 +6,11 @@ use nom::{
     sequence::separated_pair,
 };
 
+use crate::solvers::{Answer, SolveError};
 use crate::utils::Interval;
 
+pub const TAGS: &[&str] = &["intervals"];
+
 pub fn parse_is_roll(input: &str) -> IResult<&str, bool> {
     map(one_of("@."), |c| c == '@').parse(input)
 }
@@ -23,19 +26,17 @@ fn parse_ingredients(input: &str) -> IResult<&str, Vec<i64>> {
     separated_list1(line_ending, nom_i64).parse(input)
 }
 
-pub fn parse(input: &str) -> Result<(Vec<Interval>, Vec<i64>), String> {
+pub fn parse(input: &str) -> Result<(Vec<Interval>, Vec<i64>), SolveError> {
     let (input, intervals) = separated_list1(line_ending, parse_interval)
-        .parse(input)
-        .map_err(|e| e.to_string())?;
+        .parse(input)?;
 
     let (_, ingredients) = all_consuming(parse_ingredients)
-        .parse(input.trim())
-        .map_err(|e| e.to_string())?;
+        .parse(input.trim())?;
 
     Ok((intervals, ingredients))
 }
 
-pub fn part1(input: &str) -> Result<String, String> {
+pub fn part1(input: &str) -> Result<Answer, SolveError> {
     let (ranges, ingredients) = parse(input)?;
 
     let fresh_count = ingredients
@@ -43,10 +44,10 @@ pub fn part1(input: &str) -> Result<String, String> {
         .filter(move |ingredient| ranges.iter().any(|range| range.includes(*ingredient)))
         .count();
 
-    Ok(fresh_count.to_string())
+    Ok(fresh_count.into())
 }
 
-pub fn part2(input: &str) -> Result<String, String> {
+pub fn part2(input: &str) -> Result<Answer, SolveError> {
     let (mut ranges, _) = parse(input)?;
 
     'outer: loop {
@@ -65,5 +66,5 @@ pub fn part2(input: &str) -> Result<String, String> {
 
     let total_fresh: usize = ranges.into_iter().map(|range| range.len()).sum();
 
-    Ok(total_fresh.to_string())
+    Ok(total_fresh.into())
 }
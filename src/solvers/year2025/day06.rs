@@ -6,6 +6,8 @@ use nom::{
     sequence::separated_pair,
 };
 
+use crate::solvers::{Answer, SolveError};
+
 fn do_math(op: char, values: Vec<i64>) -> i64 {
     match op {
         '*' => values.into_iter().product::<i64>(),
@@ -24,19 +26,17 @@ mod part1 {
         separated_list1(space1, anychar).parse(input.trim())
     }
 
-    pub fn parse(input: &str) -> Result<(Vec<Vec<i64>>, Vec<char>), String> {
+    pub fn parse(input: &str) -> Result<(Vec<Vec<i64>>, Vec<char>), SolveError> {
         let (input, operands) = separated_list1(multispace1, parse_operand_row)
-            .parse(input)
-            .map_err(|e| e.to_string())?;
+            .parse(input)?;
         let (_, operators) = all_consuming(parse_operator_row)
-            .parse(input.trim())
-            .map_err(|e| e.to_string())?;
+            .parse(input.trim())?;
 
         Ok((operands, operators))
     }
 }
 
-pub fn part1(input: &str) -> Result<String, String> {
+pub fn part1(input: &str) -> Result<Answer, SolveError> {
     let (operands, operators) = part1::parse(input)?;
 
     let total: i64 = operators
@@ -48,18 +48,17 @@ pub fn part1(input: &str) -> Result<String, String> {
         })
         .sum();
 
-    Ok(total.to_string())
+    Ok(total.into())
 }
 
-pub fn transpose_input(input: &str) -> Result<String, String> {
+pub fn transpose_input(input: &str) -> Result<String, SolveError> {
     let n = input.lines().next().map_or(0, str::len);
     for line in input.lines().skip(1) {
         if line.len() != n {
-            return Err(format!(
+            return Err(SolveError::InvalidInput(format!(
                 "input does not have consistent length, expected {n} found {}",
                 line.len()
-            )
-            .to_string());
+            )));
         }
     }
 
@@ -91,20 +90,19 @@ mod part2 {
         Ok((input, (values, op)))
     }
 
-    pub fn parse(input: &str) -> Result<Vec<Group>, String> {
+    pub fn parse(input: &str) -> Result<Vec<Group>, SolveError> {
         let (_, groups) = all_consuming(separated_list1(multispace1, parse_group))
-            .parse(input.trim())
-            .map_err(|e| e.to_string())?;
+            .parse(input.trim())?;
         Ok(groups)
     }
 }
 
-pub fn part2(input: &str) -> Result<String, String> {
+pub fn part2(input: &str) -> Result<Answer, SolveError> {
     let input = transpose_input(input)?;
     let groups = part2::parse(&input)?;
     let total: i64 = groups
         .into_iter()
         .map(|(values, op)| do_math(op, values))
         .sum();
-    Ok(total.to_string())
+    Ok(total.into())
 }
@@ -1,5 +1,9 @@
 use std::collections::{HashMap, HashSet};
 
+use crate::solvers::{Answer, SolveError};
+
+pub const TAGS: &[&str] = &["grid", "simulation"];
+
 pub fn parse(input: &str) -> (Vec<HashSet<i64>>, i64) {
     let mut rows = Vec::new();
     let mut start = 0;
@@ -25,7 +29,7 @@ pub fn parse(input: &str) -> (Vec<HashSet<i64>>, i64) {
 }
 
 #[allow(clippy::unnecessary_wraps)]
-pub fn part1(input: &str) -> Result<String, String> {
+pub fn part1(input: &str) -> Result<Answer, SolveError> {
     let (rows, start) = parse(input);
     let mut tachs = HashSet::from([start]);
     let mut splits = 0;
@@ -43,11 +47,11 @@ pub fn part1(input: &str) -> Result<String, String> {
         }
         tachs = new_tachs;
     }
-    Ok(splits.to_string())
+    Ok(splits.into())
 }
 
 #[allow(clippy::unnecessary_wraps)]
-pub fn part2(input: &str) -> Result<String, String> {
+pub fn part2(input: &str) -> Result<Answer, SolveError> {
     let (rows, start) = parse(input);
     let mut tachs = HashMap::from([(start, 1_i64)]);
     for splitters in rows {
@@ -63,5 +67,5 @@ pub fn part2(input: &str) -> Result<String, String> {
         tachs = new_tachs;
     }
     let total: i64 = tachs.values().sum();
-    Ok(total.to_string())
+    Ok(total.into())
 }
@@ -7,8 +7,12 @@ use nom::{
     multi::separated_list1,
 };
 
+use crate::solvers::{Answer, SolveError};
+
+pub const TAGS: &[&str] = &["graph", "geometry"];
+
 #[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
-struct Junction {
+pub struct Junction {
     pub x: i64,
     pub y: i64,
     pub z: i64,
@@ -20,7 +24,7 @@ impl Junction {
     }
 }
 
-fn parse(input: &str) -> IResult<&str, Vec<Junction>> {
+fn parse_junctions(input: &str) -> IResult<&str, Vec<Junction>> {
     all_consuming(separated_list1(
         line_ending,
         map(
@@ -31,6 +35,11 @@ fn parse(input: &str) -> IResult<&str, Vec<Junction>> {
     .parse(input.trim())
 }
 
+pub fn parse(input: &str) -> Result<Vec<Junction>, SolveError> {
+    let (_, junctions) = parse_junctions(input)?;
+    Ok(junctions)
+}
+
 fn sorted_distances(junctions: &[Junction]) -> Vec<(usize, usize, i64)> {
     let mut networks = Vec::with_capacity(junctions.len() * junctions.len());
     for (i, junction) in junctions.iter().enumerate() {
@@ -75,10 +84,11 @@ fn connect_closest(j1: usize, j2: usize, networks: &mut Vec<Network>) -> bool {
     }
 }
 
-pub fn part1(input: &str) -> Result<String, String> {
-    let (_, junctions) = parse(input).map_err(|e| e.to_string())?;
-    let num_connections = if junctions.len() < 30 { 10 } else { 1000 };
-    let distances = sorted_distances(&junctions);
+pub fn part1(junctions: &[Junction]) -> Result<Answer, SolveError> {
+    // the examples connect fewer junctions, set by the manifest or guessed from the input size
+    let num_connections = crate::solvers::param("connections")
+        .unwrap_or(if junctions.len() < 30 { 10 } else { 1000 });
+    let distances = sorted_distances(junctions);
 
     let mut networks: Vec<Network> = Vec::new();
     for (i, j, _) in distances.into_iter().take(num_connections) {
@@ -96,12 +106,11 @@ pub fn part1(input: &str) -> Result<String, String> {
         total *= networks.remove(max_idx).len();
     }
 
-    Ok(total.to_string())
+    Ok(total.into())
 }
 
-pub fn part2(input: &str) -> Result<String, String> {
-    let (_, junctions) = parse(input).map_err(|e| e.to_string())?;
-    let distances = sorted_distances(&junctions);
+pub fn part2(junctions: &[Junction]) -> Result<Answer, SolveError> {
+    let distances = sorted_distances(junctions);
 
     let mut networks: Vec<Network> = Vec::new();
     let mut last_connected = (0, 0);
@@ -112,5 +121,5 @@ pub fn part2(input: &str) -> Result<String, String> {
     }
 
     let result = junctions[last_connected.0].x * junctions[last_connected.1].x;
-    Ok(result.to_string())
+    Ok(result.into())
 }
@@ -1,5 +1,3 @@
-use std::collections::{HashMap, HashSet};
-
 use nom::{
     IResult, Parser,
     character::complete::{char, i64 as nom_i64, line_ending},
@@ -7,6 +5,10 @@ use nom::{
     sequence::separated_pair,
 };
 
+use crate::solvers::{Answer, SolveError};
+
+pub const TAGS: &[&str] = &["geometry"];
+
 fn parse(input: &str) -> IResult<&str, Vec<(i64, i64)>> {
     separated_list1(line_ending, separated_pair(nom_i64, char(','), nom_i64)).parse(input)
 }
@@ -18,8 +20,8 @@ fn rect_size(a: (i64, i64), b: (i64, i64)) -> i64 {
     width * height
 }
 
-pub fn part1(input: &str) -> Result<String, String> {
-    let (_, tiles) = parse(input).map_err(|e| e.to_string())?;
+pub fn part1(input: &str) -> Result<Answer, SolveError> {
+    let (_, tiles) = parse(input)?;
 
     let mut max_rectangle: i64 = 0;
     for i in 0..tiles.len() {
@@ -28,7 +30,7 @@ pub fn part1(input: &str) -> Result<String, String> {
         }
     }
 
-    Ok(max_rectangle.to_string())
+    Ok(max_rectangle.into())
 }
 
 #[derive(Clone, Copy, Debug, Default)]
@@ -83,12 +85,6 @@ impl Line {
     }
 }
 
-#[derive(Clone, Copy, Debug, Default)]
-struct Rectangle {
-    from: (i64, i64),
-    to: (i64, i64),
-}
-
 fn rectangle_valid(from: (i64, i64), to: (i64, i64), lines: &[Line]) -> bool {
     let rect_lines = [
         Line::new(from, (to.0, from.1)), // top
@@ -104,8 +100,8 @@ fn rectangle_valid(from: (i64, i64), to: (i64, i64), lines: &[Line]) -> bool {
     })
 }
 
-pub fn part2(input: &str) -> Result<String, String> {
-    let (_, tiles) = parse(input).map_err(|e| e.to_string())?;
+pub fn part2(input: &str) -> Result<Answer, SolveError> {
+    let (_, tiles) = parse(input)?;
 
     let mut lines = Vec::new();
     for i in 0..tiles.len() {
@@ -122,7 +118,7 @@ pub fn part2(input: &str) -> Result<String, String> {
         }
     }
 
-    Ok(max_rectangle.to_string())
+    Ok(max_rectangle.into())
 }
 
 #[cfg(test)]
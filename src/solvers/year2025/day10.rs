@@ -8,6 +8,10 @@ use nom::{
     sequence::delimited,
 };
 
+use crate::solvers::{Answer, SolveError};
+
+pub const TAGS: &[&str] = &["dp"];
+
 type Button = Vec<usize>;
 type Joltage = Vec<usize>;
 
@@ -111,12 +115,10 @@ fn solve(machine: &Machine) -> i64 {
     value
 }
 
-pub fn part1(input: &str) -> Result<String, String> {
-    let (_, machines) = parse(input).map_err(|e| e.to_string())?;
+pub fn part1(input: &str) -> Result<Answer, SolveError> {
+    let (_, machines) = parse(input)?;
     let total: i64 = machines.iter().map(solve).sum();
-    Ok(total.to_string())
+    Ok(total.into())
 }
 
-pub fn part2(input: &str) -> Result<String, String> {
-    Ok(1.to_string())
-}
+crate::part2_todo!();
@@ -11,6 +11,10 @@ use nom::{
 
 use nalgebra::DMatrix;
 
+use crate::solvers::{Answer, SolveError};
+
+pub const TAGS: &[&str] = &["graph"];
+
 fn parse_device(input: &str) -> IResult<&str, String> {
     map(alpha1, String::from).parse(input)
 }
@@ -50,8 +54,8 @@ fn to_device_tree(devices: Vec<(String, Vec<String>)>) -> (usize, usize, DeviceT
     )
 }
 
-pub fn part1(input: &str) -> Result<String, String> {
-    let (_, devices) = parse(input).map_err(|e| e.to_string())?;
+pub fn part1(input: &str) -> Result<Answer, SolveError> {
+    let (_, devices) = parse(input)?;
     let (start_idx, goal_idx, tree) = to_device_tree(devices);
     let mut adjacency = DMatrix::<usize>::zeros(tree.len(), tree.len());
     for (i, row) in tree.iter().enumerate() {
@@ -78,9 +82,7 @@ pub fn part1(input: &str) -> Result<String, String> {
     }
 
     let num_paths = total_paths[(start_idx, goal_idx)];
-    Ok(num_paths.to_string())
+    Ok(num_paths.into())
 }
 
-pub fn part2(input: &str) -> Result<String, String> {
-    Ok(1.to_string())
-}
+crate::part2_todo!();
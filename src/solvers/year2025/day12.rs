@@ -7,6 +7,8 @@ use nom::{
     sequence::separated_pair,
 };
 
+use crate::solvers::{Answer, SolveError};
+
 struct Gift(Vec<Vec<bool>>);
 
 struct Region {
@@ -48,8 +50,8 @@ impl Gift {
     }
 }
 
-pub fn part1(input: &str) -> Result<String, String> {
-    let (_, (gifts, regions)) = parse(input.trim()).map_err(|e| e.to_string())?;
+pub fn part1(input: &str) -> Result<Answer, SolveError> {
+    let (_, (gifts, regions)) = parse(input.trim())?;
     let possible_regions = regions
         .iter()
         .map(|reg| {
@@ -64,9 +66,7 @@ pub fn part1(input: &str) -> Result<String, String> {
         })
         .sum::<i64>();
     let possible_regions = possible_regions - i64::from(possible_regions < 10); // extremely important check
-    Ok(possible_regions.to_string())
+    Ok(possible_regions.into())
 }
 
-pub fn part2(input: &str) -> Result<String, String> {
-    Ok(1.to_string())
-}
+crate::part2_todo!();
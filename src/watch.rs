@@ -0,0 +1,105 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use crate::input;
+use crate::solvers::Day;
+
+/// How often the watched files are checked for changes
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The modification times of all files below the watched paths
+type Snapshot = Vec<(PathBuf, SystemTime)>;
+
+/// Re-runs the given days whenever their sources, inputs or solutions change. Every run rebuilds
+/// the solvers with cargo and checks the examples first, the real inputs only run once all
+/// examples pass. `args` are passed on to every run. This never returns.
+pub fn watch(days: &[(&'static str, &'static Day)], args: &[String]) -> ! {
+    let selection = days
+        .iter()
+        .map(|(year, day)| format!("{year}:{}", day.number()))
+        .collect::<Vec<_>>()
+        .join(",");
+    let mut paths = vec![Path::new(env!("CARGO_MANIFEST_DIR")).join("src")];
+    paths.extend(
+        days.iter()
+            .map(|(year, day)| input::day_dir(year, day.name)),
+    );
+
+    let mut previous = None;
+    loop {
+        let current = snapshot(&paths);
+        if previous.as_ref() != Some(&current) {
+            previous = Some(current);
+            eprintln!("\n[watch] running {selection}");
+            let examples_pass = run(&selection, args, "--examples-only", &["--check"]);
+            if examples_pass {
+                run(&selection, args, "--real-only", &[]);
+            } else {
+                eprintln!("[watch] the examples fail, skipping the real inputs");
+            }
+            eprintln!("[watch] waiting for changes");
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// Runs the selected days with cargo and returns whether the run succeeded
+fn run(selection: &str, args: &[String], inputs: &str, extra: &[&str]) -> bool {
+    let status = Command::new(option_env!("CARGO").unwrap_or("cargo"))
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args(["run", "--quiet", "--"])
+        .args(["--days", selection, inputs])
+        .args(extra)
+        .args(args)
+        .status();
+    match status {
+        Ok(status) => status.success(),
+        Err(e) => {
+            eprintln!("[watch] failed to run cargo: {e}");
+            false
+        }
+    }
+}
+
+/// Collects the modification times of the files below `paths`, missing paths are skipped
+fn snapshot(paths: &[PathBuf]) -> Snapshot {
+    let mut files = vec![];
+    let mut pending = paths.to_vec();
+    while let Some(path) = pending.pop() {
+        let Ok(metadata) = fs::metadata(&path) else {
+            continue;
+        };
+        if metadata.is_dir() {
+            if let Ok(entries) = fs::read_dir(&path) {
+                pending.extend(entries.filter_map(|entry| Some(entry.ok()?.path())));
+            }
+        } else if let Ok(modified) = metadata.modified() {
+            files.push((path, modified));
+        }
+    }
+    files.sort();
+    files
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snapshot_detects_changes() {
+        let dir = std::env::temp_dir().join(format!("aoc-rust-watch-{}", std::process::id()));
+        fs::create_dir_all(dir.join("nested")).unwrap();
+        fs::write(dir.join("nested").join("part1.txt"), "1").unwrap();
+
+        let before = snapshot(std::slice::from_ref(&dir));
+        assert_eq!(before.len(), 1);
+        assert_eq!(before, snapshot(std::slice::from_ref(&dir)));
+
+        fs::write(dir.join("solution1.txt"), "2").unwrap();
+        assert_ne!(before, snapshot(std::slice::from_ref(&dir)));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}